@@ -5,10 +5,14 @@
 compile_error!(
     "You have the `http` or `gateway` feature enabled, either the `rustls_backend` or \
     `native_tls_backend` feature must be selected to let Serenity use `http` or `gateway`.\n\
-    - `rustls_backend` uses Rustls, a pure Rust TLS-implemenation.\n\
+    - `rustls_backend` uses Rustls, a pure Rust TLS-implementation.\n\
     - `native_tls_backend` uses SChannel on Windows, Secure Transport on macOS, and OpenSSL on \
     other platforms.\n\
     If you are unsure, go with `rustls_backend`."
 );
 
-fn main() {}
+fn main() {
+    // Used by `cfg`s that are enabled from outside of cargo, e.g. via RUSTFLAGS.
+    println!("cargo:rustc-check-cfg=cfg(simd_json)");
+    println!("cargo:rustc-check-cfg=cfg(tokio_unstable)");
+}
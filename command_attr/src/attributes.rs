@@ -193,7 +193,7 @@ impl AttributeOption for bool {
     fn parse(values: Values) -> Result<Self> {
         validate(&values, &[ValueKind::Name, ValueKind::SingleList])?;
 
-        Ok(values.literals.first().map_or(true, LitExt::to_bool))
+        Ok(values.literals.first().is_none_or(LitExt::to_bool))
     }
 }
 
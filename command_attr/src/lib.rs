@@ -62,6 +62,7 @@ macro_rules! match_options {
 /// | `#[usage(use)]` <br /> `#[usage = use]`                                        | The command's intended usage.                                                                            | `use` is a string stating the schema for the command's usage.                                                                                                                                                                     |
 /// | `#[example(ex)]` <br /> `#[example = ex]`                                      | An example of the command's usage. May be called multiple times to add many examples at once.            | `ex` is a string                                                                                                                                                                                                                  |
 /// | `#[delimiters(delims)]`                                                        | Argument delimiters specific to this command. Overrides the global list of delimiters in the framework.  | `delims` is a comma separated list of strings                                                                                                                                                                                     |
+/// | `#[rest]` <br /> `#[rest(b)]`                                                  | If the remainder of the message is passed to the command as a single trailing argument.                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                   |
 /// | `#[min_args(min)]` <br /> `#[max_args(max)]` <br /> `#[num_args(min_and_max)]` | The expected length of arguments that the command must receive in order to function correctly.           | `min`, `max` and `min_and_max` are 16-bit, unsigned integers.                                                                                                                                                                     |
 /// | `#[required_permissions(perms)]`                                               | Set of permissions the user must possess. <br /> In order for this attribute to work, "Presence Intent" and "Server Member Intent" options in bot application must be enabled and all intent flags must be enabled during client creation. | `perms` is a comma separated list of permission names.<br /> These can be found at [Discord's official documentation](https://discord.com/developers/docs/topics/permissions).        |
 /// | `#[allowed_roles(roles)]`                                                      | Set of roles the user must possess.                                                                      | `roles` is a comma separated list of role names.                                                                                                                                                                                  |
@@ -69,6 +70,7 @@ macro_rules! match_options {
 /// | `#[only_in(ctx)]`                                                              | Which environment the command can be executed in.                                                        | `ctx` is a string with the accepted values `guild`/`guilds` and `dm`/`dms` (Direct Message).                                                                                                                                      |
 /// | `#[bucket(name)]` <br /> `#[bucket = name]`                                    | What bucket will impact this command.                                                                    | `name` is a string containing the bucket's name.<br /> Refer to [the bucket example in the standard framework](https://docs.rs/serenity/*/serenity/framework/standard/struct.StandardFramework.html#method.bucket) for its usage. |
 /// | `#[owners_only]` <br /> `#[owners_only(b)]`                                    | If this command is exclusive to owners.                                                                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                   |
+/// | `#[nsfw_only]` <br /> `#[nsfw_only(b)]`                                        | If this command can only be used in NSFW channels.                                                       | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                   |
 /// | `#[owner_privilege]` <br /> `#[owner_privilege(b)]`                            | If owners can bypass certain options.                                                                    | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                   |
 /// | `#[sub_commands(commands)]`                                                    | The sub or children commands of this command. They are executed in the form: `this-command sub-command`. | `commands` is a comma separated list of identifiers referencing functions marked by the `#[command]` macro.                                                                                                                       |
 ///
@@ -135,6 +137,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
                     bucket;
                     aliases;
                     delimiters;
+                    rest;
                     usage;
                     min_args;
                     max_args;
@@ -142,6 +145,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
                     allowed_roles;
                     help_available;
                     only_in;
+                    nsfw_only;
                     owners_only;
                     owner_privilege;
                     sub_commands
@@ -156,6 +160,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         aliases,
         description,
         delimiters,
+        rest,
         usage,
         examples,
         min_args,
@@ -164,6 +169,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         required_permissions,
         help_available,
         only_in,
+        nsfw_only,
         owners_only,
         owner_privilege,
         sub_commands,
@@ -200,6 +206,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             names: &[#_name, #(#aliases),*],
             desc: #description,
             delimiters: &[#(#delimiters),*],
+            rest: #rest,
             usage: #usage,
             examples: &[#(#examples),*],
             min_args: #min_args,
@@ -208,6 +215,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             required_permissions: #required_permissions,
             help_available: #help_available,
             only_in: #only_in,
+            nsfw_only: #nsfw_only,
             owners_only: #owners_only,
             owner_privilege: #owner_privilege,
             sub_commands: &[#(&#sub_commands),*],
@@ -588,6 +596,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[allowed_roles(roles)]`                             | Set of roles the user must possess                                               | `roles` is a comma separated list of strings containing role names                                          |
 /// | `#[only_in(ctx)]`                                     | Which environment the command can be executed in.                                | `ctx` is a string with the accepted values `guild`/`guilds` and `dm`/ `dms` (Direct Message).               |
 /// | `#[owners_only]` <br /> `#[owners_only(b)]`           | If this command is exclusive to owners.                                          | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
+/// | `#[nsfw_only]` <br /> `#[nsfw_only(b)]`               | If this group's commands can only be used in NSFW channels.                      | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
 /// | `#[owner_privilege]` <br /> `#[owner_privilege(b)]`   | If owners can bypass certain options.                                            | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
 /// | `#[help_available]` <br /> `#[help_available(b)]`     | If the group should be displayed in the help message.                            | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
 /// | `#[checks(identifiers)]`                              | Preconditions that must met before the command's execution.                      | `identifiers` is a comma separated list of identifiers referencing functions marked by the `#[check]` macro |
@@ -609,7 +618,6 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// the macro. For example: `#[group("Banana Phone")]`.
 ///
 /// [`command`]: macro@command
-
 #[proc_macro_attribute]
 pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
     let group = parse_macro_input!(input as GroupStruct);
@@ -655,6 +663,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
             _ => match_options!(name, values, options, span => [
                 prefixes;
                 only_in;
+                nsfw_only;
                 owners_only;
                 owner_privilege;
                 help_available;
@@ -671,6 +680,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
     let GroupOptions {
         prefixes,
         only_in,
+        nsfw_only,
         owners_only,
         owner_privilege,
         help_available,
@@ -708,6 +718,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
         pub static #options: #options_path = #options_path {
             prefixes: &[#(#prefixes),*],
             only_in: #only_in,
+            nsfw_only: #nsfw_only,
             owners_only: #owners_only,
             owner_privilege: #owner_privilege,
             help_available: #help_available,
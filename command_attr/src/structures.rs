@@ -101,7 +101,7 @@ fn is_cooked(attr: &Attribute) -> bool {
 }
 
 pub fn is_rustfmt_or_clippy_attr(path: &Path) -> bool {
-    path.segments.first().map_or(false, |s| s.ident == "rustfmt" || s.ident == "clippy")
+    path.segments.first().is_some_and(|s| s.ident == "rustfmt" || s.ident == "clippy")
 }
 
 /// Removes cooked attributes from a vector of attributes. Uncooked attributes are left in the
@@ -447,6 +447,7 @@ pub struct Options {
     pub aliases: Vec<String>,
     pub description: AsOption<String>,
     pub delimiters: Vec<String>,
+    pub rest: bool,
     pub usage: AsOption<String>,
     pub examples: Vec<String>,
     pub min_args: AsOption<u16>,
@@ -455,6 +456,7 @@ pub struct Options {
     pub required_permissions: Permissions,
     pub help_available: bool,
     pub only_in: OnlyIn,
+    pub nsfw_only: bool,
     pub owners_only: bool,
     pub owner_privilege: bool,
     pub sub_commands: Vec<Ident>,
@@ -621,6 +623,7 @@ impl ToTokens for GroupStruct {
 pub struct GroupOptions {
     pub prefixes: Vec<String>,
     pub only_in: OnlyIn,
+    pub nsfw_only: bool,
     pub owners_only: bool,
     pub owner_privilege: bool,
     pub help_available: bool,
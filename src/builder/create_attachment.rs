@@ -25,6 +25,10 @@ pub struct CreateAttachment {
     pub(crate) id: u64, // Placeholder ID will be filled in when sending the request
     pub filename: String,
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<String>,
 
     #[serde(skip)]
     pub data: Vec<u8>,
@@ -37,6 +41,8 @@ impl CreateAttachment {
             data: data.into(),
             filename: filename.into(),
             description: None,
+            duration_secs: None,
+            waveform: None,
             id: 0,
         }
     }
@@ -51,12 +57,10 @@ impl CreateAttachment {
         let mut data = Vec::new();
         file.read_to_end(&mut data).await?;
 
-        let filename = path.as_ref().file_name().ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "attachment path must not be a directory",
-            )
-        })?;
+        let filename = path
+            .as_ref()
+            .file_name()
+            .ok_or_else(|| std::io::Error::other("attachment path must not be a directory"))?;
 
         Ok(CreateAttachment::bytes(data, filename.to_string_lossy().to_string()))
     }
@@ -112,6 +116,23 @@ impl CreateAttachment {
         self.description = Some(description.into());
         self
     }
+
+    /// Attaches the metadata Discord requires for voice messages: the duration of the recording
+    /// in seconds, and a sampled waveform to preview it with (at most 256 datapoints, 1 byte
+    /// each). The waveform is base64-encoded internally.
+    ///
+    /// For this metadata to take effect, the attachment must be sent on a message with the
+    /// [`MessageFlags::IS_VOICE_MESSAGE`] flag and no other content or attachments; see the
+    /// flag's documentation for the restrictions that apply to voice messages.
+    ///
+    /// [`MessageFlags::IS_VOICE_MESSAGE`]: crate::model::channel::MessageFlags::IS_VOICE_MESSAGE
+    pub fn voice_message_metadata(mut self, duration_secs: f64, waveform: &[u8]) -> Self {
+        use base64::Engine;
+
+        self.duration_secs = Some(duration_secs);
+        self.waveform = Some(base64::prelude::BASE64_STANDARD.encode(waveform));
+        self
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -255,6 +276,7 @@ impl EditAttachments {
     /// Clones all new attachments into a new Vec, keeping only data and filename, because those
     /// are needed for the multipart form data. The data is taken out of `self` in the process, so
     /// this method can only be called once.
+    #[cfg(feature = "http")]
     pub(crate) fn take_files(&mut self) -> Vec<CreateAttachment> {
         let mut id_placeholder = 0;
 
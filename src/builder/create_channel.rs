@@ -46,6 +46,10 @@ pub struct CreateChannel<'a> {
     available_tags: Vec<ForumTag>,
     #[serde(skip_serializing_if = "Option::is_none")]
     default_sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_thread_rate_limit_per_user: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_forum_layout: Option<ForumLayoutType>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -73,6 +77,8 @@ impl<'a> CreateChannel<'a> {
             default_reaction_emoji: None,
             available_tags: Vec::new(),
             default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
+            default_forum_layout: None,
         }
     }
 
@@ -252,11 +258,31 @@ impl<'a> CreateChannel<'a> {
         self.default_sort_order = Some(default_sort_order);
         self
     }
+
+    /// The initial rate_limit_per_user to set on newly created threads in a channel. This field is
+    /// copied to the thread at creation time and does not live update.
+    ///
+    /// Only for [`ChannelType::Text`], [`ChannelType::News`], [`ChannelType::Forum`]
+    pub fn default_thread_rate_limit_per_user(
+        mut self,
+        default_thread_rate_limit_per_user: u16,
+    ) -> Self {
+        self.default_thread_rate_limit_per_user = Some(default_thread_rate_limit_per_user);
+        self
+    }
+
+    /// The default forum layout type used to display posts in forum channels
+    ///
+    /// Only for [`ChannelType::Forum`]
+    pub fn default_forum_layout(mut self, default_forum_layout: ForumLayoutType) -> Self {
+        self.default_forum_layout = Some(default_forum_layout);
+        self
+    }
 }
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for CreateChannel<'a> {
+impl Builder for CreateChannel<'_> {
     type Context<'ctx> = GuildId;
     type Built = GuildChannel;
 
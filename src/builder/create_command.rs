@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use super::Builder;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
+#[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
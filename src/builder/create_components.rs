@@ -152,23 +152,23 @@ impl Serialize for CreateSelectMenuKind {
         }
 
         fn map<I: Into<Mention> + Copy>(
-            values: &Option<Vec<I>>,
+            values: Option<&Vec<I>>,
         ) -> impl Iterator<Item = CreateSelectMenuDefault> + '_ {
-            // Calling `.iter().flatten()` on the `Option` treats `None` like an empty vec
-            values.iter().flatten().map(|&i| CreateSelectMenuDefault(i.into()))
+            // Calling `.into_iter().flatten()` on the `Option` treats `None` like an empty vec
+            values.into_iter().flatten().map(|&i| CreateSelectMenuDefault(i.into()))
         }
 
         #[rustfmt::skip]
         let default_values = match self {
             Self::String { .. } => vec![],
-            Self::User { default_users: default_values } => map(default_values).collect(),
-            Self::Role { default_roles: default_values } => map(default_values).collect(),
+            Self::User { default_users: default_values } => map(default_values.as_ref()).collect(),
+            Self::Role { default_roles: default_values } => map(default_values.as_ref()).collect(),
             Self::Mentionable { default_users, default_roles } => {
-                let users = map(default_users);
-                let roles = map(default_roles);
+                let users = map(default_users.as_ref());
+                let roles = map(default_roles.as_ref());
                 users.chain(roles).collect()
             },
-            Self::Channel { channel_types: _, default_channels: default_values } => map(default_values).collect(),
+            Self::Channel { channel_types: _, default_channels: default_values } => map(default_values.as_ref()).collect(),
         };
 
         #[rustfmt::skip]
@@ -400,3 +400,364 @@ impl CreateInputText {
         self
     }
 }
+
+/// A builder for creating a top-level component of a message.
+///
+/// Only valid in messages with the [`MessageFlags::IS_COMPONENTS_V2`] flag, with the exception of
+/// [`Self::ActionRow`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#component-object-component-types).
+#[derive(Clone, Debug)]
+#[must_use]
+pub enum CreateComponent {
+    ActionRow(CreateActionRow),
+    Section(CreateSection),
+    TextDisplay(CreateTextDisplay),
+    MediaGallery(CreateMediaGallery),
+    File(CreateFile),
+    Separator(CreateSeparator),
+    Container(CreateContainer),
+}
+
+impl Serialize for CreateComponent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(x) => x.serialize(serializer),
+            Self::Section(x) => x.serialize(serializer),
+            Self::TextDisplay(x) => x.serialize(serializer),
+            Self::MediaGallery(x) => x.serialize(serializer),
+            Self::File(x) => x.serialize(serializer),
+            Self::Separator(x) => x.serialize(serializer),
+            Self::Container(x) => x.serialize(serializer),
+        }
+    }
+}
+
+impl From<CreateActionRow> for CreateComponent {
+    fn from(row: CreateActionRow) -> Self {
+        Self::ActionRow(row)
+    }
+}
+
+/// A builder for creating a section component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#section).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSection(Section);
+
+impl CreateSection {
+    /// Creates a section with the given text displays and accessory.
+    pub fn new(components: Vec<CreateTextDisplay>, accessory: CreateSectionAccessory) -> Self {
+        Self(Section {
+            kind: ComponentType::Section,
+            id: None,
+            components: components.into_iter().map(|c| c.0).collect(),
+            accessory: accessory.into(),
+        })
+    }
+
+    /// Adds a text display to this section.
+    pub fn add_text_display(mut self, text_display: CreateTextDisplay) -> Self {
+        self.0.components.push(text_display.0);
+        self
+    }
+
+    /// Sets the accessory of this section, replacing the current value as set in [`Self::new`].
+    pub fn accessory(mut self, accessory: CreateSectionAccessory) -> Self {
+        self.0.accessory = accessory.into();
+        self
+    }
+}
+
+/// A builder for creating the accessory of a [`CreateSection`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#section).
+#[derive(Clone, Debug)]
+#[must_use]
+pub enum CreateSectionAccessory {
+    Button(CreateButton),
+    Thumbnail(CreateThumbnail),
+}
+
+impl From<CreateSectionAccessory> for SectionAccessory {
+    fn from(accessory: CreateSectionAccessory) -> Self {
+        match accessory {
+            CreateSectionAccessory::Button(button) => Self::Button(button.0),
+            CreateSectionAccessory::Thumbnail(thumbnail) => Self::Thumbnail(thumbnail.0),
+        }
+    }
+}
+
+/// A builder for creating a text display component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#text-display).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateTextDisplay(TextDisplay);
+
+impl CreateTextDisplay {
+    /// Creates a text display with the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self(TextDisplay {
+            kind: ComponentType::TextDisplay,
+            id: None,
+            content: content.into(),
+        })
+    }
+
+    /// Sets the content of this text display, replacing the current value as set in [`Self::new`].
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = content.into();
+        self
+    }
+}
+
+/// A builder for creating a thumbnail accessory in a section.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#thumbnail).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateThumbnail(Thumbnail);
+
+impl CreateThumbnail {
+    /// Creates a thumbnail displaying the media at the given URL, which supports both HTTP(S) and
+    /// `attachment://<filename>` references.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self(Thumbnail {
+            kind: ComponentType::Thumbnail,
+            id: None,
+            media: UnfurledMediaItem {
+                url: url.into(),
+                proxy_url: None,
+                height: None,
+                width: None,
+                content_type: None,
+            },
+            description: None,
+            spoiler: false,
+        })
+    }
+
+    /// Sets the alt text of the media.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the media is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.0.spoiler = spoiler;
+        self
+    }
+}
+
+/// A builder for creating a media gallery component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#media-gallery).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateMediaGallery(MediaGallery);
+
+impl CreateMediaGallery {
+    /// Creates a media gallery with the given items.
+    ///
+    /// **Note**: A gallery must contain between 1 and 10 items.
+    pub fn new(items: Vec<CreateMediaGalleryItem>) -> Self {
+        Self(MediaGallery {
+            kind: ComponentType::MediaGallery,
+            id: None,
+            items: items.into_iter().map(|i| i.0).collect(),
+        })
+    }
+
+    /// Adds an item to this media gallery.
+    pub fn add_item(mut self, item: CreateMediaGalleryItem) -> Self {
+        self.0.items.push(item.0);
+        self
+    }
+}
+
+/// A builder for creating an item of a [`CreateMediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#media-gallery).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateMediaGalleryItem(MediaGalleryItem);
+
+impl CreateMediaGalleryItem {
+    /// Creates a media gallery item displaying the media at the given URL, which supports both
+    /// HTTP(S) and `attachment://<filename>` references.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self(MediaGalleryItem {
+            media: UnfurledMediaItem {
+                url: url.into(),
+                proxy_url: None,
+                height: None,
+                width: None,
+                content_type: None,
+            },
+            description: None,
+            spoiler: false,
+        })
+    }
+
+    /// Sets the alt text of the media.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the media is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.0.spoiler = spoiler;
+        self
+    }
+}
+
+/// A builder for creating a file component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#file).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateFile(FileComponent);
+
+impl CreateFile {
+    /// Creates a file component displaying the attachment with the given filename.
+    ///
+    /// Note however, you have to be sure you set an attachment (with [`ChannelId::send_files`])
+    /// with the provided filename. Or else this won't work.
+    ///
+    /// [`ChannelId::send_files`]: crate::model::id::ChannelId::send_files
+    pub fn new(filename: impl Into<String>) -> Self {
+        let mut url = filename.into();
+        url.insert_str(0, "attachment://");
+        Self(FileComponent {
+            kind: ComponentType::File,
+            id: None,
+            file: UnfurledMediaItem {
+                url,
+                proxy_url: None,
+                height: None,
+                width: None,
+                content_type: None,
+            },
+            spoiler: false,
+            name: None,
+            size: None,
+        })
+    }
+
+    /// Sets whether the file is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.0.spoiler = spoiler;
+        self
+    }
+}
+
+/// A builder for creating a separator component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#separator).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSeparator(Separator);
+
+impl CreateSeparator {
+    /// Creates a separator with a visible divider and small padding.
+    pub fn new() -> Self {
+        Self(Separator {
+            kind: ComponentType::Separator,
+            id: None,
+            divider: true,
+            spacing: None,
+        })
+    }
+
+    /// Sets whether a visual divider is displayed.
+    pub fn divider(mut self, divider: bool) -> Self {
+        self.0.divider = divider;
+        self
+    }
+
+    /// Sets the size of the separator padding.
+    pub fn spacing(mut self, spacing: SeparatorSpacingSize) -> Self {
+        self.0.spacing = Some(spacing);
+        self
+    }
+}
+
+impl Default for CreateSeparator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A builder for creating a container component in a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#container).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CreateContainer {
+    components: Vec<CreateComponent>,
+    accent_colour: Option<Colour>,
+    spoiler: bool,
+}
+
+impl CreateContainer {
+    /// Creates a container with the given components.
+    pub fn new(components: Vec<CreateComponent>) -> Self {
+        Self {
+            components,
+            accent_colour: None,
+            spoiler: false,
+        }
+    }
+
+    /// Adds a component to this container.
+    pub fn add_component(mut self, component: CreateComponent) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Sets the accent colour displayed on the side of the container.
+    ///
+    /// This is an alias of [`Self::accent_colour`].
+    pub fn accent_color<C: Into<Colour>>(self, colour: C) -> Self {
+        self.accent_colour(colour)
+    }
+
+    /// Sets the accent colour displayed on the side of the container.
+    pub fn accent_colour<C: Into<Colour>>(mut self, colour: C) -> Self {
+        self.accent_colour = Some(colour.into());
+        self
+    }
+
+    /// Sets whether the container contents are blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+}
+
+impl Serialize for CreateContainer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Json<'a> {
+            #[serde(rename = "type")]
+            kind: u8,
+            components: &'a [CreateComponent],
+            #[serde(rename = "accent_color", skip_serializing_if = "Option::is_none")]
+            accent_colour: Option<Colour>,
+            spoiler: bool,
+        }
+
+        let json = Json {
+            kind: 17,
+            components: &self.components,
+            accent_colour: self.accent_colour,
+            spoiler: self.spoiler,
+        };
+
+        json.serialize(serializer)
+    }
+}
@@ -14,8 +14,6 @@
 //! [`ExecuteWebhook::embeds`]: crate::builder::ExecuteWebhook::embeds
 //! [here]: https://discord.com/developers/docs/resources/channel#embed-object
 
-#[cfg(feature = "http")]
-use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
 /// A builder to create an embed in a message
@@ -172,7 +170,7 @@ impl CreateEmbed {
     }
 
     #[cfg(feature = "http")]
-    pub(super) fn check_length(&self) -> Result<()> {
+    pub(super) fn length(&self) -> usize {
         let mut length = 0;
         if let Some(ref author) = self.0.author {
             length += author.name.chars().count();
@@ -195,8 +193,7 @@ impl CreateEmbed {
             length += title.chars().count();
         }
 
-        super::check_overflow(length, crate::constants::EMBED_MAX_LENGTH)
-            .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))
+        length
     }
 }
 
@@ -227,6 +224,12 @@ impl From<Embed> for CreateEmbed {
     }
 }
 
+impl From<CreateEmbed> for Embed {
+    fn from(builder: CreateEmbed) -> Self {
+        builder.0
+    }
+}
+
 /// A builder to create the author data of an emebd. See [`CreateEmbed::author`]
 #[derive(Clone, Debug, Serialize)]
 #[must_use]
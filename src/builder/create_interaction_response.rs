@@ -104,9 +104,9 @@ impl CreateInteractionResponse {
                 check_overflow(embeds.len(), constants::EMBED_MAX_COUNT)
                     .map_err(|_| Error::Model(ModelError::EmbedAmount))?;
 
-                for embed in embeds {
-                    embed.check_length()?;
-                }
+                let embeds_length = embeds.iter().map(CreateEmbed::length).sum();
+                check_overflow(embeds_length, constants::EMBED_MAX_LENGTH)
+                    .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))?;
             }
         }
         Ok(())
@@ -266,7 +266,7 @@ impl CreateInteractionResponseMessage {
             flags |= InteractionResponseFlags::EPHEMERAL;
         } else {
             flags &= !InteractionResponseFlags::EPHEMERAL;
-        };
+        }
 
         self.flags = Some(flags);
         self
@@ -50,9 +50,9 @@ impl CreateInteractionResponseFollowup {
 
         check_overflow(self.embeds.len(), constants::EMBED_MAX_COUNT)
             .map_err(|_| Error::Model(ModelError::EmbedAmount))?;
-        for embed in &self.embeds {
-            embed.check_length()?;
-        }
+        let embeds_length = self.embeds.iter().map(CreateEmbed::length).sum();
+        check_overflow(embeds_length, constants::EMBED_MAX_LENGTH)
+            .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))?;
 
         Ok(())
     }
@@ -148,7 +148,7 @@ impl CreateInteractionResponseFollowup {
             flags |= MessageFlags::EPHEMERAL;
         } else {
             flags &= !MessageFlags::EPHEMERAL;
-        };
+        }
 
         self.flags = Some(flags);
         self
@@ -4,6 +4,7 @@ use super::{
     CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     EditAttachments,
 };
@@ -63,7 +64,7 @@ pub struct CreateMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Vec<CreateActionRow>>,
+    components: Option<Vec<CreateComponent>>,
     sticker_ids: Vec<StickerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
@@ -88,9 +89,9 @@ impl CreateMessage {
 
         check_overflow(self.embeds.len(), constants::EMBED_MAX_COUNT)
             .map_err(|_| Error::Model(ModelError::EmbedAmount))?;
-        for embed in &self.embeds {
-            embed.check_length()?;
-        }
+        let embeds_length = self.embeds.iter().map(CreateEmbed::length).sum();
+        check_overflow(embeds_length, constants::EMBED_MAX_LENGTH)
+            .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))?;
 
         check_overflow(self.sticker_ids.len(), constants::STICKER_MAX_COUNT)
             .map_err(|_| Error::Model(ModelError::StickerAmount))?;
@@ -211,10 +212,21 @@ impl CreateMessage {
 
     /// Sets the components of this message.
     pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
+        self.components = Some(components.into_iter().map(CreateComponent::ActionRow).collect());
+        self
+    }
+
+    /// Sets the top-level components of this message, additionally setting the
+    /// [`MessageFlags::IS_COMPONENTS_V2`] flag to allow the components V2 layout.
+    ///
+    /// **Note**: Once the flag is set, the message cannot have `content`, `embeds`, `stickers` or
+    /// `poll` set, and the flag cannot be removed when editing.
+    pub fn components_v2(mut self, components: Vec<CreateComponent>) -> Self {
         self.components = Some(components);
+        self.flags = Some(self.flags.unwrap_or_default() | MessageFlags::IS_COMPONENTS_V2);
         self
     }
-    super::button_and_select_menu_convenience_methods!(self.components);
+    super::button_and_select_menu_convenience_methods!(v2: self.components);
 
     /// Sets the flags for the message.
     pub fn flags(mut self, flags: MessageFlags) -> Self {
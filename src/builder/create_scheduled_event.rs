@@ -124,7 +124,7 @@ impl<'a> CreateScheduledEvent<'a> {
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for CreateScheduledEvent<'a> {
+impl Builder for CreateScheduledEvent<'_> {
     type Context<'ctx> = GuildId;
     type Built = ScheduledEvent;
 
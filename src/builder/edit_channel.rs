@@ -69,7 +69,7 @@ pub struct EditChannel<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     default_thread_rate_limit_per_user: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    default_sort_order: Option<SortOrder>,
+    default_sort_order: Option<Option<SortOrder>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     default_forum_layout: Option<ForumLayoutType>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,7 +279,7 @@ impl<'a> EditChannel<'a> {
     }
 
     /// The default sort order type used to order posts in forum channels
-    pub fn default_sort_order(mut self, default_sort_order: SortOrder) -> Self {
+    pub fn default_sort_order(mut self, default_sort_order: Option<SortOrder>) -> Self {
         self.default_sort_order = Some(default_sort_order);
         self
     }
@@ -293,7 +293,7 @@ impl<'a> EditChannel<'a> {
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for EditChannel<'a> {
+impl Builder for EditChannel<'_> {
     type Context<'ctx> = ChannelId;
     type Built = GuildChannel;
 
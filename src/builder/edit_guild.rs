@@ -52,6 +52,8 @@ pub struct EditGuild<'a> {
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     premium_progress_bar_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety_alerts_channel_id: Option<Option<ChannelId>>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -310,11 +312,19 @@ impl<'a> EditGuild<'a> {
         self.premium_progress_bar_enabled = Some(premium_progress_bar_enabled);
         self
     }
+
+    /// Set the channel ID where admins and moderators receive safety alerts from Discord.
+    ///
+    /// **Note**: This feature is for Community guilds only.
+    pub fn safety_alerts_channel_id(mut self, channel_id: Option<ChannelId>) -> Self {
+        self.safety_alerts_channel_id = Some(channel_id);
+        self
+    }
 }
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for EditGuild<'a> {
+impl Builder for EditGuild<'_> {
     type Context<'ctx> = GuildId;
     type Built = PartialGuild;
 
@@ -0,0 +1,79 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the membership screening form of a guild
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-membership-screening-form)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditGuildMemberVerification<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form_fields: Option<Vec<VerificationFormField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditGuildMemberVerification<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the membership screening form is enabled or not.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// The steps of the form.
+    pub fn form_fields(mut self, form_fields: Vec<VerificationFormField>) -> Self {
+        self.form_fields = Some(form_fields);
+        self
+    }
+
+    /// The server description shown in the form.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditGuildMemberVerification<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = GuildMemberVerification;
+
+    /// Edits the guild's membership screening form.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_guild_member_verification(ctx, &self, self.audit_log_reason).await
+    }
+}
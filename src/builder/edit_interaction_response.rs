@@ -93,6 +93,11 @@ impl EditInteractionResponse {
         Self(self.0.keep_existing_attachment(id))
     }
 
+    /// Shorthand for [`EditAttachments::remove`].
+    pub fn remove_existing_attachment(self, id: AttachmentId) -> Self {
+        Self(self.0.remove_existing_attachment(id))
+    }
+
     /// Shorthand for calling [`Self::attachments`] with [`EditAttachments::new`].
     pub fn clear_attachments(self) -> Self {
         Self(self.0.clear_attachments())
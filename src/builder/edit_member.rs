@@ -136,6 +136,16 @@ impl<'a> EditMember<'a> {
         self
     }
 
+    /// Set the member's flags.
+    ///
+    /// **Note**: [`GuildMemberFlags::BYPASSES_VERIFICATION`] is the only editable flag. Setting it
+    /// requires the [Manage Guild] permission, or the [Moderate Members], [Kick Members], and [Ban
+    /// Members] permissions combined.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Ban Members]: Permissions::BAN_MEMBERS
     pub fn flags(mut self, flags: GuildMemberFlags) -> Self {
         self.flags = Some(flags);
         self
@@ -150,7 +160,7 @@ impl<'a> EditMember<'a> {
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for EditMember<'a> {
+impl Builder for EditMember<'_> {
     type Context<'ctx> = (GuildId, UserId);
     type Built = Member;
 
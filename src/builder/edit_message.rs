@@ -4,6 +4,7 @@ use super::{
     CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     EditAttachments,
 };
@@ -47,7 +48,7 @@ pub struct EditMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Vec<CreateActionRow>>,
+    components: Option<Vec<CreateComponent>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     attachments: Option<EditAttachments>,
 }
@@ -68,9 +69,9 @@ impl EditMessage {
         if let Some(embeds) = &self.embeds {
             check_overflow(embeds.len(), constants::EMBED_MAX_COUNT)
                 .map_err(|_| Error::Model(ModelError::EmbedAmount))?;
-            for embed in embeds {
-                embed.check_length()?;
-            }
+            let embeds_length = embeds.iter().map(CreateEmbed::length).sum();
+            check_overflow(embeds_length, constants::EMBED_MAX_LENGTH)
+                .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))?;
         }
 
         Ok(())
@@ -155,8 +156,7 @@ impl EditMessage {
     pub fn suppress_embeds(mut self, suppress: bool) -> Self {
         // At time of writing, only `SUPPRESS_EMBEDS` can be set/unset when editing messages. See
         // for details: https://discord.com/developers/docs/resources/channel#edit-message-jsonform-params
-        let flags =
-            suppress.then_some(MessageFlags::SUPPRESS_EMBEDS).unwrap_or_else(MessageFlags::empty);
+        let flags = if suppress { MessageFlags::SUPPRESS_EMBEDS } else { MessageFlags::empty() };
 
         self.flags = Some(flags);
         self
@@ -170,10 +170,21 @@ impl EditMessage {
 
     /// Sets the components of this message.
     pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
+        self.components = Some(components.into_iter().map(CreateComponent::ActionRow).collect());
+        self
+    }
+
+    /// Sets the top-level components of this message, additionally setting the
+    /// [`MessageFlags::IS_COMPONENTS_V2`] flag to allow the components V2 layout.
+    ///
+    /// **Note**: Once the flag is set, the message cannot have `content`, `embeds`, `stickers` or
+    /// `poll` set, and the flag cannot be removed when editing.
+    pub fn components_v2(mut self, components: Vec<CreateComponent>) -> Self {
         self.components = Some(components);
+        self.flags = Some(self.flags.unwrap_or_default() | MessageFlags::IS_COMPONENTS_V2);
         self
     }
-    super::button_and_select_menu_convenience_methods!(self.components);
+    super::button_and_select_menu_convenience_methods!(v2: self.components);
 
     /// Sets the flags for the message.
     pub fn flags(mut self, flags: MessageFlags) -> Self {
@@ -203,6 +214,15 @@ impl EditMessage {
         self
     }
 
+    /// Shorthand for calling [`Self::attachments`] with [`EditAttachments::keep_all`], keeping
+    /// all of the message's existing attachments.
+    ///
+    /// **Note**: Overwrites any previous calls to attachment methods on this builder.
+    pub fn keep_existing_attachments(mut self, msg: &Message) -> Self {
+        self.attachments = Some(EditAttachments::keep_all(msg));
+        self
+    }
+
     /// Shorthand for [`EditAttachments::remove`].
     pub fn remove_existing_attachment(mut self, id: AttachmentId) -> Self {
         if let Some(attachments) = self.attachments {
@@ -0,0 +1,200 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the onboarding flow of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-onboarding)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditOnboarding<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompts: Option<Vec<CreateOnboardingPrompt>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_channel_ids: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<OnboardingMode>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditOnboarding<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The prompts shown during onboarding and in the Channels & Roles tab.
+    pub fn prompts(mut self, prompts: Vec<CreateOnboardingPrompt>) -> Self {
+        self.prompts = Some(prompts);
+        self
+    }
+
+    /// The channels that members get opted into automatically.
+    pub fn default_channel_ids(mut self, default_channel_ids: Vec<ChannelId>) -> Self {
+        self.default_channel_ids = Some(default_channel_ids);
+        self
+    }
+
+    /// Whether onboarding is enabled in the guild.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// The criteria mode used to validate the onboarding configuration.
+    pub fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditOnboarding<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = GuildOnboarding;
+
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the new configuration
+    /// does not satisfy the onboarding constraints.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_guild_onboarding(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// A builder for creating an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPrompt(OnboardingPrompt);
+
+impl CreateOnboardingPrompt {
+    pub fn new(title: impl Into<String>, kind: OnboardingPromptType) -> Self {
+        Self(OnboardingPrompt {
+            id: OnboardingPromptId::new(1),
+            kind,
+            options: Vec::new(),
+            title: title.into(),
+            single_select: false,
+            required: false,
+            in_onboarding: true,
+        })
+    }
+
+    /// The Id of an existing prompt to update in place.
+    ///
+    /// Prompts with an Id not currently part of the onboarding flow are created anew, so this can
+    /// be left unset for new prompts.
+    pub fn id(mut self, id: OnboardingPromptId) -> Self {
+        self.0.id = id;
+        self
+    }
+
+    /// The title of the prompt.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    /// The options available within the prompt.
+    pub fn options(mut self, options: Vec<CreateOnboardingPromptOption>) -> Self {
+        self.0.options = options.into_iter().map(|option| option.0).collect();
+        self
+    }
+
+    /// Whether users are limited to selecting one option for the prompt.
+    pub fn single_select(mut self, single_select: bool) -> Self {
+        self.0.single_select = single_select;
+        self
+    }
+
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.required = required;
+        self
+    }
+
+    /// Whether the prompt is present in the onboarding flow. If `false`, the prompt will only
+    /// appear in the Channels & Roles tab.
+    pub fn in_onboarding(mut self, in_onboarding: bool) -> Self {
+        self.0.in_onboarding = in_onboarding;
+        self
+    }
+}
+
+/// A builder for creating an [`OnboardingPromptOption`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPromptOption(OnboardingPromptOption);
+
+impl CreateOnboardingPromptOption {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self(OnboardingPromptOption {
+            id: OnboardingPromptOptionId::new(1),
+            channel_ids: Vec::new(),
+            role_ids: Vec::new(),
+            emoji: None,
+            title: title.into(),
+            description: None,
+        })
+    }
+
+    /// The title of the option.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    /// The description of the option.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    /// The channels a member is added to when the option is selected.
+    pub fn channel_ids(mut self, channel_ids: Vec<ChannelId>) -> Self {
+        self.0.channel_ids = channel_ids;
+        self
+    }
+
+    /// The roles assigned to a member when the option is selected.
+    pub fn role_ids(mut self, role_ids: Vec<RoleId>) -> Self {
+        self.0.role_ids = role_ids;
+        self
+    }
+
+    /// The emoji shown for the option.
+    pub fn emoji(mut self, emoji: OnboardingPromptOptionEmoji) -> Self {
+        self.0.emoji = Some(emoji);
+        self
+    }
+}
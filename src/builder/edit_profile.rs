@@ -19,6 +19,8 @@ pub struct EditProfile {
     username: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    banner: Option<Option<String>>,
 }
 
 impl EditProfile {
@@ -55,6 +57,18 @@ impl EditProfile {
         self
     }
 
+    /// Set the banner of the current user.
+    pub fn banner(mut self, banner: &CreateAttachment) -> Self {
+        self.banner = Some(Some(banner.to_base64()));
+        self
+    }
+
+    /// Delete the current user's banner.
+    pub fn delete_banner(mut self) -> Self {
+        self.banner = Some(None);
+        self
+    }
+
     /// Modifies the current user's username.
     ///
     /// When modifying the username, if another user has the same _new_ username and current
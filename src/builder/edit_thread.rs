@@ -77,6 +77,7 @@ impl<'a> EditThread<'a> {
     /// Amount of seconds a user has to wait before sending another message (0-21600); bots, as well
     /// as users with the permission manage_messages, manage_thread, or manage_channel, are
     /// unaffected
+    #[doc(alias = "slowmode")]
     pub fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
         self.rate_limit_per_user = Some(rate_limit_per_user);
         self
@@ -104,7 +105,7 @@ impl<'a> EditThread<'a> {
 
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
-impl<'a> Builder for EditThread<'a> {
+impl Builder for EditThread<'_> {
     type Context<'ctx> = ChannelId;
     type Built = GuildChannel;
 
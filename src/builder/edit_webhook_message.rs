@@ -52,9 +52,9 @@ impl EditWebhookMessage {
         if let Some(embeds) = &self.embeds {
             check_overflow(embeds.len(), constants::EMBED_MAX_COUNT)
                 .map_err(|_| Error::Model(ModelError::EmbedAmount))?;
-            for embed in embeds {
-                embed.check_length()?;
-            }
+            let embeds_length = embeds.iter().map(CreateEmbed::length).sum();
+            check_overflow(embeds_length, constants::EMBED_MAX_LENGTH)
+                .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))?;
         }
 
         Ok(())
@@ -153,6 +153,14 @@ impl EditWebhookMessage {
         self
     }
 
+    /// Shorthand for [`EditAttachments::remove`].
+    pub fn remove_existing_attachment(mut self, id: AttachmentId) -> Self {
+        if let Some(attachments) = self.attachments {
+            self.attachments = Some(attachments.remove(id));
+        }
+        self
+    }
+
     /// Shorthand for calling [`Self::attachments`] with [`EditAttachments::new`].
     pub fn clear_attachments(mut self) -> Self {
         self.attachments = Some(EditAttachments::new());
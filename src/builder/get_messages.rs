@@ -116,6 +116,8 @@ impl Builder for GetMessages {
 }
 
 #[derive(Clone, Copy, Debug)]
+// The fields are only read when executing the request.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
 enum SearchFilter {
     After(MessageId),
     Around(MessageId),
@@ -59,11 +59,13 @@ mod create_webhook;
 mod edit_automod_rule;
 mod edit_channel;
 mod edit_guild;
+mod edit_guild_member_verification;
 mod edit_guild_welcome_screen;
 mod edit_guild_widget;
 mod edit_interaction_response;
 mod edit_member;
 mod edit_message;
+mod edit_onboarding;
 mod edit_profile;
 mod edit_role;
 mod edit_scheduled_event;
@@ -99,11 +101,13 @@ pub use create_webhook::*;
 pub use edit_automod_rule::*;
 pub use edit_channel::*;
 pub use edit_guild::*;
+pub use edit_guild_member_verification::*;
 pub use edit_guild_welcome_screen::*;
 pub use edit_guild_widget::*;
 pub use edit_interaction_response::*;
 pub use edit_member::*;
 pub use edit_message::*;
+pub use edit_onboarding::*;
 pub use edit_profile::*;
 pub use edit_role::*;
 pub use edit_scheduled_event::*;
@@ -145,6 +149,41 @@ macro_rules! button_and_select_menu_convenience_methods {
             $self
         }
     };
+    // Variant for builders whose components field holds top-level `CreateComponent`s.
+    (v2: $self:ident $(. $components_path:tt)+) => {
+        /// Adds a clickable button to this message.
+        ///
+        /// Convenience method that wraps [`Self::components`]. Arranges buttons in action rows
+        /// automatically.
+        pub fn button(mut $self, button: super::CreateButton) -> Self {
+            let components = $self$(.$components_path)+.get_or_insert_with(Vec::new);
+            let row_with_space_left = components.last_mut().and_then(|component| match component {
+                super::CreateComponent::ActionRow(super::CreateActionRow::Buttons(buttons))
+                    if buttons.len() < 5 =>
+                {
+                    Some(buttons)
+                },
+                _ => None,
+            });
+            match row_with_space_left {
+                Some(row) => row.push(button),
+                None => components.push(super::CreateComponent::ActionRow(
+                    super::CreateActionRow::Buttons(vec![button]),
+                )),
+            }
+            $self
+        }
+
+        /// Adds an interactive select menu to this message.
+        ///
+        /// Convenience method that wraps [`Self::components`].
+        pub fn select_menu(mut $self, select_menu: super::CreateSelectMenu) -> Self {
+            $self$(.$components_path)+.get_or_insert_with(Vec::new).push(
+                super::CreateComponent::ActionRow(super::CreateActionRow::SelectMenu(select_menu)),
+            );
+            $self
+        }
+    };
 }
 
 use button_and_select_menu_convenience_methods;
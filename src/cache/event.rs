@@ -224,6 +224,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                     premium_since: self.premium_since,
                     permissions: None,
                     avatar: self.avatar,
+                    banner: None,
                     communication_disabled_until: self.communication_disabled_until,
                     flags: GuildMemberFlags::default(),
                 });
@@ -414,6 +415,7 @@ impl CacheUpdate for PresenceUpdateEvent {
                         premium_since: None,
                         permissions: None,
                         avatar: None,
+                        banner: None,
                         communication_disabled_until: None,
                         flags: GuildMemberFlags::default(),
                     });
@@ -92,7 +92,7 @@ pub(crate) fn dispatch_model(
 ///
 /// Can return `None` if an event is unknown.
 #[cfg_attr(not(feature = "cache"), allow(unused_mut))]
-fn update_cache_with_event(
+pub(crate) fn update_cache_with_event(
     #[cfg(feature = "cache")] cache: &Cache,
     event: Event,
 ) -> Option<(FullEvent, Option<FullEvent>)> {
@@ -266,6 +266,20 @@ fn update_cache_with_event(
                 new: event.role,
             }
         },
+        Event::GuildSoundboardSoundCreate(event) => FullEvent::GuildSoundboardSoundCreate {
+            sound: event.sound,
+        },
+        Event::GuildSoundboardSoundUpdate(event) => FullEvent::GuildSoundboardSoundUpdate {
+            sound: event.sound,
+        },
+        Event::GuildSoundboardSoundDelete(event) => FullEvent::GuildSoundboardSoundDelete {
+            guild_id: event.guild_id,
+            sound_id: event.sound_id,
+        },
+        Event::GuildSoundboardSoundsUpdate(event) => FullEvent::GuildSoundboardSoundsUpdate {
+            guild_id: event.guild_id,
+            current_state: event.soundboard_sounds,
+        },
         Event::GuildStickersUpdate(mut event) => {
             update_cache!(cache, event);
 
@@ -233,6 +233,27 @@ event_handler! {
     /// available) and new data.
     async fn guild_role_update(&self, ctx: Context, | GuildRoleUpdate {  old_data_if_available: Option<Role>, new: Role });
 
+    /// Dispatched when a soundboard sound is created.
+    ///
+    /// Provides said sound's data.
+    async fn guild_soundboard_sound_create(&self, ctx: Context, | GuildSoundboardSoundCreate {  sound: SoundboardSound });
+
+    /// Dispatched when a soundboard sound is updated.
+    ///
+    /// Provides said sound's new data.
+    async fn guild_soundboard_sound_update(&self, ctx: Context, | GuildSoundboardSoundUpdate {  sound: SoundboardSound });
+
+    /// Dispatched when a soundboard sound is deleted.
+    ///
+    /// Provides the guild's id and the deleted sound's id.
+    async fn guild_soundboard_sound_delete(&self, ctx: Context, | GuildSoundboardSoundDelete {  guild_id: GuildId, sound_id: SoundboardSoundId });
+
+    /// Dispatched when multiple soundboard sounds are updated, e.g. after a loss of server
+    /// boosts.
+    ///
+    /// Provides the guild's id and the new state of the guild's sounds.
+    async fn guild_soundboard_sounds_update(&self, ctx: Context, | GuildSoundboardSoundsUpdate {  guild_id: GuildId, current_state: Vec<SoundboardSound> });
+
     /// Dispatched when the stickers are updated.
     ///
     /// Provides the guild's id and the new state of the stickers in the guild.
@@ -20,6 +20,8 @@ pub(crate) mod dispatch;
 mod error;
 #[cfg(feature = "gateway")]
 mod event_handler;
+#[cfg(feature = "testing")]
+mod testing;
 
 use std::future::IntoFuture;
 use std::ops::Range;
@@ -38,6 +40,8 @@ pub use self::context::Context;
 pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
 pub use self::event_handler::{EventHandler, FullEvent, RawEventHandler};
+#[cfg(feature = "testing")]
+pub use self::testing::EventInjector;
 #[cfg(feature = "gateway")]
 use super::gateway::GatewayError;
 #[cfg(feature = "cache")]
@@ -297,6 +301,17 @@ impl ClientBuilder {
         self
     }
 
+    /// Adds an event handler with a single method where all received gateway events will be
+    /// dispatched. Passed by Arc.
+    pub fn raw_event_handler_arc<H: RawEventHandler + 'static>(
+        mut self,
+        raw_event_handler_arc: Arc<H>,
+    ) -> Self {
+        self.raw_event_handlers.push(raw_event_handler_arc);
+
+        self
+    }
+
     /// Gets the added raw event handlers. See [`Self::raw_event_handler`] for more info.
     pub fn get_raw_event_handlers(&self) -> &[Arc<dyn RawEventHandler>] {
         &self.raw_event_handlers
@@ -543,6 +558,35 @@ pub struct Client {
     ///
     /// Refer to [example 05] for an example on using the [`Self::data`] field.
     ///
+    /// # Avoiding deadlocks
+    ///
+    /// Note that the returned lock is exclusive: holding a write guard across an `.await` that
+    /// itself tries to lock the data — such as a handler for an event triggered by your own
+    /// actions — will deadlock. Acquire the guard in a small scope, clone or copy what you need,
+    /// and drop it before doing further work.
+    ///
+    /// For values that are internally synchronised, such as database pools or anything wrapped in
+    /// an [`Arc`], store the handle itself as the [`TypeMapKey::Value`] and clone it out of the
+    /// map:
+    ///
+    /// ```rust,ignore
+    /// struct Database;
+    ///
+    /// impl TypeMapKey for Database {
+    ///     type Value = Arc<DatabasePool>;
+    /// }
+    ///
+    /// async fn handle(ctx: &Context) {
+    ///     // The lock is only held long enough to clone the Arc.
+    ///     let pool = {
+    ///         let data = ctx.data.read().await;
+    ///         Arc::clone(data.get::<Database>().unwrap())
+    ///     };
+    ///
+    ///     pool.execute("...").await;
+    /// }
+    /// ```
+    ///
     /// [`Event::MessageCreate`]: crate::model::event::Event::MessageCreate
     /// [`Event::MessageDelete`]: crate::model::event::Event::MessageDelete
     /// [`Event::MessageDeleteBulk`]: crate::model::event::Event::MessageDeleteBulk
@@ -661,6 +705,10 @@ impl Client {
     /// # }
     /// ```
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to an error.
+    ///
     /// [gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<()> {
@@ -0,0 +1,182 @@
+//! A harness for integration-testing [`EventHandler`] implementations offline.
+
+use std::sync::Arc;
+
+use futures::channel::mpsc::UnboundedReceiver;
+use tokio::sync::RwLock;
+use typemap_rev::TypeMap;
+
+use super::dispatch::update_cache_with_event;
+use super::{Context, EventHandler};
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+use crate::gateway::{ShardMessenger, ShardRunnerMessage};
+use crate::http::Http;
+use crate::model::event::Event;
+use crate::model::id::ShardId;
+
+/// Feeds synthetic gateway [`Event`]s through the normal dispatch path into an [`EventHandler`],
+/// so bot logic can be integration-tested without connecting to Discord.
+///
+/// Handlers receive a [`Context`] backed by an in-memory cache which each injected event updates
+/// exactly as real gateway traffic would. Unlike the [`Client`] dispatch loop, which spawns
+/// handler invocations as tasks, [`Self::dispatch`] awaits the handler, so assertions can run
+/// right after it returns.
+///
+/// The context's shard messenger is not connected to a shard; messages sent through it are
+/// discarded. To also stub the REST API, pass an [`Http`] configured with a
+/// [`MockTransport`](crate::http::MockTransport).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "testing", feature = "cache"))]
+/// # async fn run() {
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// use serenity::client::EventInjector;
+/// use serenity::http::Http;
+/// use serenity::json::{from_value, json};
+/// use serenity::model::channel::Message;
+/// use serenity::model::event::Event;
+/// use serenity::prelude::*;
+///
+/// #[derive(Default)]
+/// struct Handler(AtomicUsize);
+///
+/// #[serenity::async_trait]
+/// impl EventHandler for Handler {
+///     async fn message(&self, _ctx: Context, _msg: Message) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let handler = Arc::new(Handler::default());
+/// let injector = EventInjector::new(handler.clone(), Arc::new(Http::new("")));
+///
+/// let event: Event = from_value(json!({
+///     "t": "MESSAGE_CREATE",
+///     "d": { /* message payload */ }
+/// }))
+/// .unwrap();
+/// injector.dispatch(event).await;
+///
+/// assert_eq!(handler.0.load(Ordering::Relaxed), 1);
+/// # }
+/// ```
+///
+/// [`Client`]: super::Client
+pub struct EventInjector {
+    context: Context,
+    handlers: Vec<Arc<dyn EventHandler>>,
+    // Keeps the shard messenger's channel alive so that sends do not error.
+    _shard_rx: UnboundedReceiver<ShardRunnerMessage>,
+}
+
+impl EventInjector {
+    /// Creates a harness dispatching to the given handler, with an empty cache.
+    #[must_use]
+    pub fn new(handler: Arc<dyn EventHandler>, http: Arc<Http>) -> Self {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let shard = ShardMessenger {
+            tx,
+            #[cfg(feature = "collector")]
+            collectors: Arc::default(),
+        };
+
+        let context = Context {
+            data: Arc::new(RwLock::new(TypeMap::new())),
+            shard,
+            shard_id: ShardId(0),
+            http,
+            #[cfg(feature = "cache")]
+            cache: Arc::new(Cache::default()),
+        };
+
+        Self {
+            context,
+            handlers: vec![handler],
+            _shard_rx: rx,
+        }
+    }
+
+    /// Registers an additional handler, dispatched after the existing ones.
+    pub fn add_handler(&mut self, handler: Arc<dyn EventHandler>) {
+        self.handlers.push(handler);
+    }
+
+    /// The context passed to handlers. Its data map and cache can be seeded before dispatching.
+    #[must_use]
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Updates the cache with the event, then dispatches the resulting event to every registered
+    /// handler, awaiting each invocation in turn.
+    ///
+    /// Secondary "virtual" events like [`FullEvent::CacheReady`] are dispatched first, mirroring
+    /// the real dispatch loop.
+    ///
+    /// [`FullEvent::CacheReady`]: super::FullEvent::CacheReady
+    pub async fn dispatch(&self, event: Event) {
+        let Some((event, extra_event)) = update_cache_with_event(
+            #[cfg(feature = "cache")]
+            &self.context.cache,
+            event,
+        ) else {
+            return;
+        };
+
+        for event in extra_event.into_iter().chain(std::iter::once(event)) {
+            for handler in &self.handlers {
+                event.clone().dispatch(self.context.clone(), &**handler).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::EventInjector;
+    use crate::client::{Context, EventHandler};
+    use crate::http::Http;
+    use crate::json::{from_value, json};
+    use crate::model::event::{Event, TypingStartEvent};
+
+    #[derive(Default)]
+    struct Handler(AtomicUsize);
+
+    #[crate::async_trait]
+    impl EventHandler for Handler {
+        async fn typing_start(&self, _ctx: Context, _event: TypingStartEvent) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[tokio::test]
+    async fn injected_events_reach_handlers() {
+        let handler = Arc::new(Handler::default());
+        let mut injector = EventInjector::new(Arc::clone(&handler) as _, Arc::new(Http::new("")));
+
+        let second = Arc::new(Handler::default());
+        injector.add_handler(Arc::clone(&second) as _);
+
+        let event: Event = from_value(json!({
+            "t": "TYPING_START",
+            "d": {
+                "channel_id": "381880193700069377",
+                "user_id": "240050941876477952",
+                "timestamp": 1_700_000_000,
+            }
+        }))
+        .unwrap();
+        Box::pin(injector.dispatch(event)).await;
+
+        assert_eq!(handler.0.load(Ordering::Relaxed), 1);
+        assert_eq!(second.0.load(Ordering::Relaxed), 1);
+    }
+}
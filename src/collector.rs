@@ -1,3 +1,50 @@
+//! Collectors await a certain event from the gateway, without needing to wire up an
+//! [`EventHandler`] and shuttle the data back through global state.
+//!
+//! The most common entrypoints are the `await_*` methods on model types, such as
+//! [`ChannelId::await_reply`], [`Message::await_reaction`] and
+//! [`Message::await_component_interaction`]. These return a collector that can be configured with
+//! builder-like filter methods, then either awaited directly for the next matching event:
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! # use serenity::model::prelude::*;
+//! # use serenity::prelude::*;
+//! # async fn _example(ctx: &Context, channel_id: ChannelId, author_id: UserId) {
+//! let answer = channel_id
+//!     .await_reply(&ctx.shard)
+//!     .author_id(author_id)
+//!     .timeout(Duration::from_secs(30))
+//!     .await;
+//! # }
+//! ```
+//!
+//! ...or turned into a [`Stream`] with [`stream`] to collect multiple events:
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! # use futures::StreamExt as _;
+//! # use serenity::model::prelude::*;
+//! # use serenity::prelude::*;
+//! # async fn _example(ctx: &Context, channel_id: ChannelId) {
+//! let mut messages =
+//!     channel_id.await_replies(&ctx.shard).timeout(Duration::from_secs(60)).stream();
+//!
+//! while let Some(message) = messages.next().await {
+//!     println!("{}", message.content);
+//! }
+//! # }
+//! ```
+//!
+//! All of these are thin wrappers around the [`collect`] function, which taps the gateway event
+//! dispatch of a shard and can be used directly for events without a specialised collector.
+//!
+//! [`EventHandler`]: crate::client::EventHandler
+//! [`ChannelId::await_reply`]: crate::model::id::ChannelId::await_reply
+//! [`Message::await_reaction`]: crate::model::channel::Message::await_reaction
+//! [`Message::await_component_interaction`]: crate::model::channel::Message::await_component_interaction
+//! [`stream`]: MessageCollector::stream
+
 // Or we'll get deprecation warnings from our own deprecated type (seriously Rust?)
 #![allow(deprecated)]
 
@@ -10,6 +57,25 @@ use crate::model::prelude::*;
 /// Fundamental collector function. All collector types in this module are just wrappers around
 /// this function.
 ///
+/// The `extractor` doubles as the filter: return [`Some`] with the data to collect from a
+/// matching event, or [`None`] to skip it. To filter whole [`Event`]s by an arbitrary predicate,
+/// return `Some(event.clone())` for the events that pass:
+///
+/// ```rust
+/// # use futures::Stream;
+/// # use serenity::model::prelude::*;
+/// # use serenity::gateway::ShardMessenger;
+/// # use serenity::collector::collect;
+/// # fn _example(shard: &ShardMessenger, guild_id: GuildId) -> impl Stream<Item = Event> {
+/// collect(shard, move |event| match event {
+///     Event::GuildRoleCreate(GuildRoleCreateEvent {
+///         role, ..
+///     }) if role.guild_id == guild_id => Some(event.clone()),
+///     _ => None,
+/// })
+/// # }
+/// ```
+///
 /// Example: creating a collector stream over removed reactions
 /// ```rust
 /// # use std::time::Duration;
@@ -168,6 +234,7 @@ make_specific_collector!(
     guild_id: GuildId => interaction.guild_id.map_or(true, |x| x == *guild_id),
     message_id: MessageId => interaction.message.id == *message_id,
     custom_ids: Vec<String> => custom_ids.contains(&interaction.data.custom_id),
+    component_kind: ComponentType => interaction.data.kind.component_type() == *component_kind,
 );
 make_specific_collector!(
     ModalInteractionCollector, ModalInteraction,
@@ -179,6 +246,7 @@ make_specific_collector!(
     guild_id: GuildId => interaction.guild_id.map_or(true, |g| g == *guild_id),
     message_id: MessageId => interaction.message.as_ref().map_or(true, |m| m.id == *message_id),
     custom_ids: Vec<String> => custom_ids.contains(&interaction.data.custom_id),
+    custom_id_prefix: String => interaction.data.custom_id.starts_with(custom_id_prefix.as_str()),
 );
 make_specific_collector!(
     ReactionCollector, Reaction,
@@ -187,6 +255,17 @@ make_specific_collector!(
     channel_id: ChannelId => reaction.channel_id == *channel_id,
     guild_id: GuildId => reaction.guild_id.map_or(true, |g| g == *guild_id),
     message_id: MessageId => reaction.message_id == *message_id,
+    emoji: ReactionType => reaction.emoji == *emoji,
+);
+make_specific_collector!(
+    // Counterpart of ReactionCollector for removed reactions.
+    ReactionRemoveCollector, Reaction,
+    Event::ReactionRemove(ReactionRemoveEvent { reaction }) => reaction,
+    author_id: UserId => reaction.user_id.map_or(true, |a| a == *author_id),
+    channel_id: ChannelId => reaction.channel_id == *channel_id,
+    guild_id: GuildId => reaction.guild_id.map_or(true, |g| g == *guild_id),
+    message_id: MessageId => reaction.message_id == *message_id,
+    emoji: ReactionType => reaction.emoji == *emoji,
 );
 make_specific_collector!(
     MessageCollector, Message,
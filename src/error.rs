@@ -3,7 +3,7 @@ use std::fmt::{self, Error as FormatError};
 use std::io::Error as IoError;
 
 #[cfg(feature = "http")]
-use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
+use reqwest::{header::InvalidHeaderValue, Error as ReqwestError, StatusCode};
 #[cfg(feature = "gateway")]
 use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 use tracing::instrument;
@@ -88,6 +88,36 @@ pub enum Error {
     Tungstenite(TungsteniteError),
 }
 
+#[cfg(feature = "http")]
+impl Error {
+    /// Returns true when the error is an HTTP 429, i.e. the bot hit a ratelimit.
+    #[must_use]
+    pub fn is_ratelimited(&self) -> bool {
+        self.status_code() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Returns the HTTP status code if the error is caused by an unsuccessful request.
+    #[must_use]
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Self::Http(inner) => inner.status_code(),
+            _ => None,
+        }
+    }
+
+    /// Returns the [JSON error code] reported by Discord if the error is caused by an unsuccessful
+    /// request.
+    ///
+    /// [JSON error code]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json
+    #[must_use]
+    pub fn discord_error_code(&self) -> Option<isize> {
+        match self {
+            Self::Http(HttpError::UnsuccessfulRequest(res)) => Some(res.error.code),
+            _ => None,
+        }
+    }
+}
+
 impl From<FormatError> for Error {
     fn from(e: FormatError) -> Error {
         Error::Format(e)
@@ -180,6 +210,8 @@ impl StdError for Error {
             Self::Model(inner) => Some(inner),
             #[cfg(feature = "client")]
             Self::Client(inner) => Some(inner),
+            #[cfg(feature = "gateway")]
+            Self::Gateway(inner) => Some(inner),
             #[cfg(feature = "http")]
             Self::Http(inner) => Some(inner),
             #[cfg(feature = "gateway")]
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::time::Duration;
 
 use futures::future::BoxFuture;
 
@@ -111,11 +112,13 @@ pub struct Configuration {
     pub(crate) allowed_channels: HashSet<ChannelId>,
     pub(crate) disabled_commands: HashSet<String>,
     pub(crate) dynamic_prefixes: Vec<DynamicPrefixHook>,
+    pub(crate) edit_tracking: Option<Duration>,
     pub(crate) ignore_bots: bool,
     pub(crate) ignore_webhooks: bool,
     pub(crate) on_mention: Option<String>,
     pub(crate) owners: HashSet<UserId>,
     pub(crate) prefixes: Vec<String>,
+    pub(crate) suggestion_distance: usize,
     pub(crate) no_dm_prefix: bool,
     pub(crate) delimiters: Vec<Delimiter>,
     pub(crate) case_insensitive: bool,
@@ -351,6 +354,61 @@ impl Configuration {
         self
     }
 
+    /// Enables tracking of command invocations for the given window of time after dispatch.
+    ///
+    /// While a command invocation is tracked, editing the invocation message within the window
+    /// will re-run the command with the edited content. Commands may additionally record the
+    /// message they replied with via [`StandardFramework::track_response`] and, upon being
+    /// re-run, retrieve it with [`StandardFramework::tracked_response`] in order to edit their
+    /// previous reply instead of sending a new one.
+    ///
+    /// **Note**: Defaults to `None`, disabling edit tracking entirely.
+    ///
+    /// # Examples
+    ///
+    /// Re-dispatch commands whose invocation message is edited within a minute:
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use serenity::framework::standard::{Configuration, StandardFramework};
+    ///
+    /// let framework = StandardFramework::new();
+    /// framework.configure(Configuration::new().edit_tracking(Some(Duration::from_secs(60))));
+    /// ```
+    ///
+    /// [`StandardFramework::track_response`]: super::StandardFramework::track_response
+    /// [`StandardFramework::tracked_response`]: super::StandardFramework::tracked_response
+    #[must_use]
+    pub fn edit_tracking(mut self, window: Option<Duration>) -> Self {
+        self.edit_tracking = window;
+        self
+    }
+
+    /// The maximum Levenshtein distance at which an unrecognised command name is considered a
+    /// typo of a registered command, making the registered command eligible to be suggested via
+    /// [`StandardFramework::suggestions`].
+    ///
+    /// **Note**: Defaults to `0`, disabling suggestions entirely.
+    ///
+    /// # Examples
+    ///
+    /// Suggest commands that are at most two edits away from the unrecognised name:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{Configuration, StandardFramework};
+    ///
+    /// let framework = StandardFramework::new();
+    /// framework.configure(Configuration::new().suggestion_distance(2));
+    /// ```
+    ///
+    /// [`StandardFramework::suggestions`]: super::StandardFramework::suggestions
+    #[must_use]
+    pub fn suggestion_distance(mut self, distance: usize) -> Self {
+        self.suggestion_distance = distance;
+        self
+    }
+
     /// Whether the bot should respond to other bots.
     ///
     /// For example, if this is set to false, then the bot will respond to any other bots including
@@ -578,12 +636,14 @@ impl Default for Configuration {
     /// - **delimiters** to `vec![' ']`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefixes** to an empty vector
+    /// - **edit_tracking** to `None`
     /// - **ignore_bots** to `true`
     /// - **ignore_webhooks** to `true`
     /// - **no_dm_prefix** to `false`
     /// - **on_mention** to `false`
     /// - **owners** to an empty HashSet
     /// - **prefix** to "~"
+    /// - **suggestion_distance** to `0`
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
@@ -596,12 +656,14 @@ impl Default for Configuration {
             delimiters: vec![Delimiter::Single(' ')],
             disabled_commands: HashSet::default(),
             dynamic_prefixes: Vec::new(),
+            edit_tracking: None,
             ignore_bots: true,
             ignore_webhooks: true,
             no_dm_prefix: false,
             on_mention: None,
             owners: HashSet::default(),
             prefixes: vec![String::from("~")],
+            suggestion_distance: 0,
         }
     }
 }
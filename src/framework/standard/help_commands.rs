@@ -492,8 +492,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
                     &help_options.dm_and_guild_text
                 };
 
-                similar_commands
-                    .sort_unstable_by(|a, b| a.levenshtein_distance.cmp(&b.levenshtein_distance));
+                similar_commands.sort_unstable_by_key(|a| a.levenshtein_distance);
 
                 let check_names: Vec<String> = command
                     .options
@@ -687,12 +686,12 @@ fn fetch_all_eligible_commands_in_group<'rec, 'a: 'rec>(
 
 /// Fetch groups with their commands.
 #[cfg(feature = "cache")]
-async fn create_command_group_commands_pair_from_groups<'a>(
+async fn create_command_group_commands_pair_from_groups(
     ctx: &Context,
     msg: &Message,
     groups: &[&'static CommandGroup],
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
-    help_options: &'a HelpOptions,
+    help_options: &HelpOptions,
 ) -> Vec<GroupCommandsPair> {
     let mut listed_groups: Vec<GroupCommandsPair> = Vec::default();
 
@@ -901,7 +900,7 @@ fn flatten_group_to_string(
     let repeated_indent_str = help_options.indention_prefix.repeat(nest_level);
 
     if nest_level > 0 {
-        writeln!(group_text, "{repeated_indent_str}__**{}**__", group.name,)?;
+        writeln!(group_text, "{repeated_indent_str}__**{}**__", group.name)?;
     }
 
     let mut summary_or_prefixes = false;
@@ -920,7 +919,7 @@ fn flatten_group_to_string(
             group.prefixes.join("`, `"),
         )?;
         summary_or_prefixes = true;
-    };
+    }
 
     if summary_or_prefixes {
         writeln!(group_text)?;
@@ -959,7 +958,8 @@ fn flatten_group_to_plain_string(
     let repeated_indent_str = help_options.indention_prefix.repeat(nest_level);
 
     if nest_level > 0 {
-        write!(group_text, "\n{repeated_indent_str}**{}**", group.name).unwrap();
+        write!(group_text, "\n{repeated_indent_str}**{}**", group.name)
+            .expect("writing to a String cannot fail");
     }
 
     if group.prefixes.is_empty() {
@@ -970,7 +970,7 @@ fn flatten_group_to_plain_string(
             " ({}: `{}`): ",
             help_options.group_prefix,
             group.prefixes.join("`, `"),
-        ).unwrap();
+        ).expect("writing to a String cannot fail");
     }
 
     let joined_commands = group.command_names.join(", ");
@@ -1232,7 +1232,7 @@ fn grouped_commands_to_plain_string(
     result.push('\n');
 
     for group in groups {
-        write!(result, "\n**{}**", &group.name).unwrap();
+        write!(result, "\n**{}**", &group.name).expect("writing to a String cannot fail");
 
         flatten_group_to_plain_string(&mut result, group, 0, help_options);
     }
@@ -1245,16 +1245,17 @@ fn grouped_commands_to_plain_string(
 fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<'_>) -> String {
     let mut result = String::new();
 
-    writeln!(result, "__**{}**__", command.name).unwrap();
+    writeln!(result, "__**{}**__", command.name).expect("writing to a String cannot fail");
 
     if !command.aliases.is_empty() {
         write!(result, "**{}**: `{}`", help_options.aliases_label, command.aliases.join("`, `"))
-            .unwrap();
+            .expect("writing to a String cannot fail");
     }
 
     if let Some(description) = command.description {
-        writeln!(result, "**{}**: {description}", help_options.description_label).unwrap();
-    };
+        writeln!(result, "**{}**: {description}", help_options.description_label)
+            .expect("writing to a String cannot fail");
+    }
 
     if let Some(usage) = command.usage {
         if let Some(first_prefix) = command.group_prefixes.first() {
@@ -1263,10 +1264,10 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
                 "**{}**: `{first_prefix} {} {usage}`",
                 help_options.usage_label, command.name
             )
-            .unwrap();
+            .expect("writing to a String cannot fail");
         } else {
             writeln!(result, "**{}**: `{} {usage}`", help_options.usage_label, command.name)
-                .unwrap();
+                .expect("writing to a String cannot fail");
         }
     }
 
@@ -1278,7 +1279,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
                     "**{}**: `{first_prefix} {} {example}`",
                     help_options.usage_sample_label, command.name
                 )
-                .unwrap();
+                .expect("writing to a String cannot fail");
             };
             command.usage_sample.iter().for_each(format_example);
         } else {
@@ -1288,16 +1289,18 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
                     "**{}**: `{} {example}`",
                     help_options.usage_sample_label, command.name
                 )
-                .unwrap();
+                .expect("writing to a String cannot fail");
             };
             command.usage_sample.iter().for_each(format_example);
         }
     }
 
-    writeln!(result, "**{}**: {}", help_options.grouped_label, command.group_name).unwrap();
+    writeln!(result, "**{}**: {}", help_options.grouped_label, command.group_name)
+        .expect("writing to a String cannot fail");
 
     if !help_options.available_text.is_empty() && !command.availability.is_empty() {
-        writeln!(result, "**{}**: {}", help_options.available_text, command.availability).unwrap();
+        writeln!(result, "**{}**: {}", help_options.available_text, command.availability)
+            .expect("writing to a String cannot fail");
     }
 
     if !command.sub_commands.is_empty() {
@@ -1307,7 +1310,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
             help_options.sub_commands_label,
             command.sub_commands.join("`, `"),
         )
-        .unwrap();
+        .expect("writing to a String cannot fail");
     }
 
     result
@@ -8,20 +8,23 @@ mod configuration;
 mod parse;
 mod structures;
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
 use async_trait::async_trait;
 pub use configuration::{Configuration, WithWhiteSpace};
 use futures::future::BoxFuture;
+use levenshtein::levenshtein;
 use parse::map::{CommandMap, GroupMap, Map};
 use parse::{Invoke, ParseError};
 pub use structures::buckets::BucketBuilder;
 use structures::buckets::{Bucket, RateLimitAction};
 pub use structures::*;
-use tokio::sync::Mutex;
-use tokio::time::sleep;
+use tokio::sync::{Mutex, Semaphore};
+use tokio::time::{sleep, timeout};
 use tracing::instrument;
 use uwl::Stream;
 
@@ -31,8 +34,10 @@ use super::Framework;
 use crate::cache::Cache;
 use crate::client::{Context, FullEvent};
 use crate::model::channel::Message;
+use crate::model::event::MessageUpdateEvent;
 #[cfg(feature = "cache")]
 use crate::model::guild::Member;
+use crate::model::id::{ChannelId, GuildId, MessageId, UserId};
 use crate::model::permissions::Permissions;
 #[cfg(all(feature = "cache", feature = "http", feature = "model"))]
 use crate::model::{guild::Role, id::RoleId};
@@ -45,6 +50,9 @@ pub enum DispatchError {
     CheckFailed(&'static str, Reason),
     /// When the command caller has exceeded a ratelimit bucket.
     Ratelimited(RateLimitInfo),
+    /// When the command's execution exceeded the timeout configured via
+    /// [`StandardFramework::command_timeout`].
+    CommandTimedOut(Duration),
     /// When the requested command is disabled in bot configuration.
     CommandDisabled,
     /// When the user is blocked in bot configuration.
@@ -59,6 +67,8 @@ pub enum DispatchError {
     /// When the requested command can only be ran in guilds, or the bot doesn't
     /// support DMs.
     OnlyForGuilds,
+    /// When the requested command can only be used in NSFW channels.
+    NsfwOnly,
     /// When the requested command can only be used by bot owners.
     OnlyForOwners,
     /// When the requested command requires one role.
@@ -82,9 +92,53 @@ type AfterHook = for<'fut> fn(
 ) -> BoxFuture<'fut, ()>;
 type UnrecognisedHook =
     for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>;
+type SuggestionsHook = for<'fut> fn(
+    &'fut Context,
+    &'fut Message,
+    &'fut str,
+    &'fut [&'static str],
+) -> BoxFuture<'fut, ()>;
+type MetricsHook =
+    for<'fut> fn(&'fut Context, &'fut Message, &'fut CommandMetrics) -> BoxFuture<'fut, ()>;
 type NormalMessageHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 
+/// Measurements taken for a single command invocation, passed to the hook given to
+/// [`StandardFramework::metrics`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CommandMetrics {
+    /// The primary name of the invoked command.
+    pub command_name: &'static str,
+    /// The Id of the guild the command was invoked in, if any.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the command was invoked in.
+    pub channel_id: ChannelId,
+    /// The Id of the user that invoked the command.
+    pub user_id: UserId,
+    /// How long it took to recognise the prefix and resolve the command.
+    pub parse_duration: Duration,
+    /// How long the command's handler ran for. For invocations cancelled by
+    /// [`StandardFramework::command_timeout`], this is the time until cancellation.
+    pub execution_duration: Duration,
+    /// Whether the handler returned [`Ok`]. Invocations cancelled by
+    /// [`StandardFramework::command_timeout`] are reported as failures.
+    pub success: bool,
+}
+
+/// State kept for a command invocation while edit tracking is enabled.
+///
+/// See [`Configuration::edit_tracking`] for an overview of the feature.
+#[derive(Debug)]
+struct TrackedInvocation {
+    /// The invocation message, with any edits received so far applied to it.
+    message: Message,
+    /// The reply the command registered via [`StandardFramework::track_response`], if any.
+    response: Option<MessageId>,
+    /// When the invocation was first dispatched; used to expire the entry.
+    dispatched_at: Instant,
+}
+
 /// A utility for easily managing dispatches to commands.
 ///
 /// Refer to the [module-level documentation] for more information.
@@ -92,15 +146,21 @@ type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'f
 /// [module-level documentation]: self
 #[derive(Default)]
 pub struct StandardFramework {
-    groups: Vec<(&'static CommandGroup, Map)>,
+    groups: parking_lot::RwLock<Vec<(&'static CommandGroup, Arc<Map>)>>,
     buckets: Mutex<HashMap<String, Bucket>>,
     before: Option<BeforeHook>,
     after: Option<AfterHook>,
     dispatch: Option<DispatchHook>,
     unrecognised_command: Option<UnrecognisedHook>,
+    suggestions: Option<SuggestionsHook>,
     normal_message: Option<NormalMessageHook>,
     prefix_only: Option<PrefixOnlyHook>,
     config: parking_lot::RwLock<Configuration>,
+    edit_tracker: parking_lot::Mutex<HashMap<MessageId, TrackedInvocation>>,
+    concurrency: Option<Semaphore>,
+    command_timeout: Option<Duration>,
+    broadcast_typing: bool,
+    metrics: Option<MetricsHook>,
     help: Option<&'static HelpCommand>,
     /// Whether the framework has been "initialized".
     ///
@@ -360,9 +420,15 @@ impl StandardFramework {
     /// groups in the framework; will _not_ mark the framework as initialized. Refer to
     /// [`Self::group`] for adding groups in initial configuration.
     ///
+    /// This may be called on a running framework instance, e.g. through the [`Arc`] handed to
+    /// [`ClientBuilder::framework_arc`]; messages dispatched afterwards will recognise the
+    /// group's commands.
+    ///
     /// Note: does _not_ return [`Self`] like many other commands. This is because it's not
     /// intended to be chained as the other commands are.
-    pub fn group_add(&mut self, group: &'static CommandGroup) {
+    ///
+    /// [`ClientBuilder::framework_arc`]: crate::client::ClientBuilder::framework_arc
+    pub fn group_add(&self, group: &'static CommandGroup) {
         let config = self.config.read();
         let map = if group.options.prefixes.is_empty() {
             Map::Prefixless(
@@ -373,17 +439,33 @@ impl StandardFramework {
             Map::WithPrefixes(GroupMap::new(&[group], &config))
         };
 
-        self.groups.push((group, map));
+        self.groups.write().push((group, Arc::new(map)));
     }
 
     /// Removes a group from being used in the framework. Primary use-case is runtime modification
     /// of groups in the framework.
     ///
+    /// Like [`Self::group_add`], this may be called on a running framework instance.
+    ///
     /// Note: does _not_ return [`Self`] like many other commands. This is because it's not
     /// intended to be chained as the other commands are.
-    pub fn group_remove(&mut self, group: &'static CommandGroup) {
+    pub fn group_remove(&self, group: &'static CommandGroup) {
         // Iterates through the vector and if a given group _doesn't_ match, we retain it
-        self.groups.retain(|&(g, _)| g != group);
+        self.groups.write().retain(|&(g, _)| g != group);
+    }
+
+    /// Disables the command with the given name at runtime, making the framework answer further
+    /// invocations of it with [`DispatchError::CommandDisabled`].
+    ///
+    /// This is a runtime complement to [`Configuration::disabled_commands`].
+    pub fn disable_command(&self, name: impl Into<String>) {
+        self.config.write().disabled_commands.insert(name.into());
+    }
+
+    /// Re-enables a command previously disabled via [`Self::disable_command`] or
+    /// [`Configuration::disabled_commands`].
+    pub fn enable_command(&self, name: &str) {
+        self.config.write().disabled_commands.remove(name);
     }
 
     /// Specify the function that's called in case a command wasn't executed for one reason or
@@ -563,6 +645,46 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called if an unrecognised command resembles one or more
+    /// registered commands.
+    ///
+    /// The hook receives the unrecognised name and the matching command names, ordered from
+    /// closest to furthest. It is only invoked if
+    /// [`Configuration::suggestion_distance`] is set to a non-zero value and at least one
+    /// command name is within that Levenshtein distance of the unrecognised name.
+    ///
+    /// # Examples
+    ///
+    /// Using [`Self::suggestions`]:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn suggestions_hook(
+    ///     _: &Context,
+    ///     msg: &Message,
+    ///     unrecognised_command_name: &str,
+    ///     suggestions: &[&'static str],
+    /// ) {
+    ///     println!(
+    ///         "No command named {:?}; did you mean one of {:?}?",
+    ///         unrecognised_command_name, suggestions
+    ///     );
+    /// }
+    ///
+    /// let framework = StandardFramework::new().suggestions(suggestions_hook);
+    /// ```
+    #[must_use]
+    pub fn suggestions(mut self, f: SuggestionsHook) -> Self {
+        self.suggestions = Some(f);
+
+        self
+    }
+
     /// Specify the function to be called if a message contains no command.
     ///
     /// # Examples
@@ -589,6 +711,168 @@ impl StandardFramework {
         self
     }
 
+    /// Limits how many commands may execute in parallel.
+    ///
+    /// Each command invocation is dispatched onto its own task; by default, any number of them
+    /// may run concurrently. With a limit set, invocations beyond the limit wait for a running
+    /// command to finish before executing. This is useful to shield rate-limited resources such
+    /// as database connections from bursts of commands.
+    ///
+    /// **Note**: Defaults to no limit.
+    #[must_use]
+    pub fn max_concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = Some(Semaphore::new(limit));
+
+        self
+    }
+
+    /// Broadcasts the typing indicator in the command's channel while the command executes.
+    ///
+    /// The indicator is started when a command begins executing and is re-triggered periodically
+    /// via [`Typing`] until the handler completes, signalling to users that a long-running
+    /// command is still being processed.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// [`Typing`]: crate::http::Typing
+    #[must_use]
+    pub fn broadcast_typing(mut self, broadcast_typing: bool) -> Self {
+        self.broadcast_typing = broadcast_typing;
+
+        self
+    }
+
+    /// Sets a timeout for command execution.
+    ///
+    /// A command that runs longer than `duration` is cancelled at the next await point, and the
+    /// timeout is reported to the hook given to [`Self::on_dispatch_error`] as a
+    /// [`DispatchError::CommandTimedOut`]. The [`Self::after`] hook is not called for cancelled
+    /// commands.
+    ///
+    /// **Note**: Defaults to no timeout.
+    #[must_use]
+    pub fn command_timeout(mut self, duration: Duration) -> Self {
+        self.command_timeout = Some(duration);
+
+        self
+    }
+
+    /// Specify the function to be called with a [`CommandMetrics`] once a command has finished
+    /// executing.
+    ///
+    /// This allows feeding command usage statistics into systems such as Prometheus or StatsD
+    /// without wrapping every command manually.
+    ///
+    /// # Examples
+    ///
+    /// Using [`Self::metrics`]:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::standard::CommandMetrics;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn metrics_hook(_: &Context, _: &Message, metrics: &CommandMetrics) {
+    ///     println!(
+    ///         "{} ran for {:?} (success: {})",
+    ///         metrics.command_name, metrics.execution_duration, metrics.success
+    ///     );
+    /// }
+    ///
+    /// let framework = StandardFramework::new().metrics(metrics_hook);
+    /// ```
+    #[must_use]
+    pub fn metrics(mut self, f: MetricsHook) -> Self {
+        self.metrics = Some(f);
+
+        self
+    }
+
+    /// Records `response` as the reply that was sent for the `invocation` message.
+    ///
+    /// This is a no-op unless the invocation is currently tracked, which requires
+    /// [`Configuration::edit_tracking`] to be enabled. When the invocation is edited within the
+    /// tracking window and the command is re-run, the command can fetch the recorded reply with
+    /// [`Self::tracked_response`] and edit it instead of sending a new message.
+    pub fn track_response(&self, invocation: MessageId, response: MessageId) {
+        if let Some(tracked) = self.edit_tracker.lock().get_mut(&invocation) {
+            tracked.response = Some(response);
+        }
+    }
+
+    /// Returns the reply previously recorded for the `invocation` message via
+    /// [`Self::track_response`], if the invocation is still tracked.
+    #[must_use]
+    pub fn tracked_response(&self, invocation: MessageId) -> Option<MessageId> {
+        self.edit_tracker.lock().get(&invocation).and_then(|tracked| tracked.response)
+    }
+
+    /// Applies a message update event to the tracked invocation it belongs to, returning the
+    /// updated message to be re-dispatched.
+    ///
+    /// Returns [`None`], dropping the entry if expired, when the edited message is not a tracked
+    /// invocation inside the tracking window.
+    fn apply_edit(&self, event: &MessageUpdateEvent, window: Duration) -> Option<Message> {
+        let mut tracker = self.edit_tracker.lock();
+
+        let Entry::Occupied(mut entry) = tracker.entry(event.id) else {
+            return None;
+        };
+
+        if entry.get().dispatched_at.elapsed() > window {
+            entry.remove();
+            return None;
+        }
+
+        let invocation = entry.get_mut();
+        event.apply_to_message(&mut invocation.message);
+
+        Some(invocation.message.clone())
+    }
+
+    /// Collects the names of registered commands that are within
+    /// [`Configuration::suggestion_distance`] edits of `name`, ordered from closest to furthest.
+    fn closest_commands(&self, name: &str, config: &Configuration) -> Vec<&'static str> {
+        fn collect_names(group: &CommandGroup, names: &mut Vec<&'static str>) {
+            for command in group.options.commands {
+                names.extend(command.options.names);
+            }
+
+            for sub_group in group.options.sub_groups {
+                collect_names(sub_group, names);
+            }
+        }
+
+        let name = if config.case_insensitive { name.to_lowercase() } else { name.to_string() };
+
+        let mut names = Vec::new();
+
+        for (group, _) in self.groups.read().iter() {
+            collect_names(group, &mut names);
+        }
+
+        let mut matches = names
+            .into_iter()
+            .filter_map(|candidate| {
+                let distance = if config.case_insensitive {
+                    levenshtein(&candidate.to_lowercase(), &name)
+                } else {
+                    levenshtein(candidate, &name)
+                };
+
+                (distance <= config.suggestion_distance).then_some((distance, candidate))
+            })
+            .collect::<Vec<_>>();
+
+        matches.sort_unstable();
+        matches.dedup();
+
+        matches.into_iter().map(|(_, candidate)| candidate).collect()
+    }
+
     /// Sets what code should be executed when a user sends `(prefix)help`.
     ///
     /// If a command named `help` in a group was set, then this takes precedence first.
@@ -604,17 +888,40 @@ impl StandardFramework {
 impl Framework for StandardFramework {
     #[instrument(skip(self, event))]
     async fn dispatch(&self, mut ctx: Context, event: FullEvent) {
-        let FullEvent::Message {
-            new_message: msg,
-        } = event
-        else {
-            return;
+        let msg = match event {
+            FullEvent::Message {
+                new_message,
+            } => {
+                // Expire tracked invocations whose window has elapsed, so abandoned entries
+                // don't accumulate for the lifetime of the framework.
+                if let Some(window) = self.config.read().edit_tracking {
+                    self.edit_tracker.lock().retain(|_, t| t.dispatched_at.elapsed() <= window);
+                }
+
+                new_message
+            },
+            FullEvent::MessageUpdate {
+                event, ..
+            } => {
+                let Some(window) = self.config.read().edit_tracking else {
+                    return;
+                };
+
+                let Some(msg) = self.apply_edit(&event, window) else {
+                    return;
+                };
+
+                msg
+            },
+            _ => return,
         };
 
         if self.should_ignore(&msg) {
             return;
         }
 
+        let parse_start = Instant::now();
+
         let mut stream = Stream::new(&msg.content);
 
         stream.take_while_char(char::is_whitespace);
@@ -639,16 +946,22 @@ impl Framework for StandardFramework {
             return;
         }
 
+        // Clone the group list out of the lock so that runtime additions and removals do not
+        // block, nor get blocked by, in-flight dispatches.
+        let groups = self.groups.read().clone();
+
         let invocation = parse::command(
             &ctx,
             &msg,
             &mut stream,
-            &self.groups,
+            &groups,
             &config,
             self.help.map(|h| h.options.names),
         )
         .await;
 
+        let parse_duration = parse_start.elapsed();
+
         let invoke = match invocation {
             Ok(i) => i,
             Err(ParseError::UnrecognisedCommand(unreg)) => {
@@ -656,6 +969,16 @@ impl Framework for StandardFramework {
                     if let Some(unrecognised_command) = &self.unrecognised_command {
                         unrecognised_command(&mut ctx, &msg, &unreg).await;
                     }
+
+                    if let Some(suggestions) = &self.suggestions {
+                        if config.suggestion_distance > 0 {
+                            let matches = self.closest_commands(&unreg, &config);
+
+                            if !matches.is_empty() {
+                                suggestions(&mut ctx, &msg, &unreg, &matches).await;
+                            }
+                        }
+                    }
                 }
 
                 if let Some(normal) = &self.normal_message {
@@ -684,7 +1007,7 @@ impl Framework for StandardFramework {
 
                 let args = Args::new(stream.rest(), &config.delimiters);
 
-                let groups = self.groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
+                let groups = groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
 
                 // `parse_command` promises to never return a help invocation if
                 // `StandardFramework::help` is `None`.
@@ -708,7 +1031,10 @@ impl Framework for StandardFramework {
                 command,
                 group,
             } => {
-                let mut args = {
+                let mut args = if command.options.rest {
+                    // The command takes the remainder of the message as one trailing argument.
+                    Args::new(stream.rest(), &[])
+                } else {
                     use std::borrow::Cow;
 
                     let mut delims = Cow::Borrowed(&config.delimiters);
@@ -748,13 +1074,88 @@ impl Framework for StandardFramework {
 
                 let name = command.options.names[0];
 
+                if self.config.read().edit_tracking.is_some() {
+                    match self.edit_tracker.lock().entry(msg.id) {
+                        // Re-dispatch of an already tracked invocation; the applied edit is
+                        // stored so the window and any recorded response are kept.
+                        Entry::Occupied(mut entry) => entry.get_mut().message = msg.clone(),
+                        Entry::Vacant(entry) => {
+                            entry.insert(TrackedInvocation {
+                                message: msg.clone(),
+                                response: None,
+                                dispatched_at: Instant::now(),
+                            });
+                        },
+                    }
+                }
+
                 if let Some(before) = &self.before {
                     if !before(&mut ctx, &msg, name).await {
                         return;
                     }
                 }
 
-                let res = (command.fun)(&mut ctx, &msg, args).await;
+                // Wait for a permit if the number of concurrently executing commands is limited.
+                // The semaphore is never closed, so acquiring a permit cannot fail.
+                let _permit = match &self.concurrency {
+                    Some(semaphore) => semaphore.acquire().await.ok(),
+                    None => None,
+                };
+
+                // Stopped by dropping the guard once the command has finished.
+                let _typing = self.broadcast_typing.then(|| msg.channel_id.start_typing(&ctx.http));
+
+                let execution_start = Instant::now();
+
+                let res = match self.command_timeout {
+                    Some(duration) => {
+                        let fut = timeout(duration, (command.fun)(&mut ctx, &msg, args));
+
+                        if let Ok(res) = fut.await {
+                            res
+                        } else {
+                            // Timed-out invocations still count towards usage metrics, as a
+                            // failure with the duration spent before cancellation.
+                            if let Some(metrics) = &self.metrics {
+                                let stats = CommandMetrics {
+                                    command_name: name,
+                                    guild_id: msg.guild_id,
+                                    channel_id: msg.channel_id,
+                                    user_id: msg.author.id,
+                                    parse_duration,
+                                    execution_duration: execution_start.elapsed(),
+                                    success: false,
+                                };
+
+                                metrics(&mut ctx, &msg, &stats).await;
+                            }
+
+                            if let Some(dispatch) = &self.dispatch {
+                                let error = DispatchError::CommandTimedOut(duration);
+                                dispatch(&mut ctx, &msg, error, name).await;
+                            }
+
+                            return;
+                        }
+                    },
+                    None => (command.fun)(&mut ctx, &msg, args).await,
+                };
+
+                let execution_duration = execution_start.elapsed();
+
+                if let Some(metrics) = &self.metrics {
+                    let stats = CommandMetrics {
+                        command_name: name,
+                        guild_id: msg.guild_id,
+                        channel_id: msg.channel_id,
+                        user_id: msg.author.id,
+                        parse_duration,
+                        execution_duration,
+                        success: res.is_ok(),
+                    };
+
+                    metrics(&mut ctx, &msg, &stats).await;
+                }
 
                 // Check if the command wants to revert the bucket by giving back a ticket.
                 if matches!(&res, Err(e) if e.is::<RevertBucket>()) {
@@ -778,6 +1179,7 @@ pub trait CommonOptions {
     fn allowed_roles(&self) -> &'static [&'static str];
     fn checks(&self) -> &'static [&'static Check];
     fn only_in(&self) -> OnlyIn;
+    fn nsfw_only(&self) -> bool;
     fn help_available(&self) -> bool;
     fn owners_only(&self) -> bool;
     fn owner_privilege(&self) -> bool;
@@ -800,6 +1202,10 @@ impl CommonOptions for &GroupOptions {
         self.only_in
     }
 
+    fn nsfw_only(&self) -> bool {
+        self.nsfw_only
+    }
+
     fn help_available(&self) -> bool {
         self.help_available
     }
@@ -830,6 +1236,10 @@ impl CommonOptions for &CommandOptions {
         self.only_in
     }
 
+    fn nsfw_only(&self) -> bool {
+        self.nsfw_only
+    }
+
     fn help_available(&self) -> bool {
         self.help_available
     }
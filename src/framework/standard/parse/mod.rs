@@ -7,6 +7,7 @@ pub mod map;
 use std::borrow::Cow;
 #[cfg(feature = "cache")]
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use futures::future::{BoxFuture, FutureExt};
 use map::{CommandMap, GroupMap, ParseMap};
@@ -34,7 +35,7 @@ fn permissions_in(
     roles: &HashMap<RoleId, Role>,
 ) -> Permissions {
     let guild = ctx.cache.guild(guild_id);
-    if guild.as_ref().map(|guild| member.user.id == guild.owner_id) == Some(true) {
+    if guild.as_ref().is_some_and(|guild| member.user.id == guild.owner_id) {
         return Permissions::all();
     }
 
@@ -73,7 +74,7 @@ fn permissions_in(
             }
         }
 
-        data.sort_by(|a, b| a.0.cmp(&b.0));
+        data.sort_by_key(|a| a.0);
 
         for overwrite in data {
             permissions = (permissions & !overwrite.1) | overwrite.2;
@@ -217,6 +218,17 @@ async fn check_discrepancy(
         return Err(DispatchError::OnlyForGuilds);
     }
 
+    // Direct messages are always exempt, as Discord only age-gates guild channels.
+    #[cfg(feature = "cache")]
+    if options.nsfw_only() && !msg.is_private() {
+        let is_nsfw =
+            msg.channel_id.to_channel_cached(&ctx.cache).is_some_and(|channel| channel.nsfw);
+
+        if !is_nsfw {
+            return Err(DispatchError::NsfwOnly);
+        }
+    }
+
     #[cfg(feature = "cache")]
     {
         if let Some(guild_id) = msg.guild_id {
@@ -315,7 +327,7 @@ fn parse_cmd<'a>(
             };
         }
 
-        Err(ParseError::UnrecognisedCommand(Some(n.to_string())))
+        Err(ParseError::UnrecognisedCommand(Some(n.clone())))
     }
     .boxed()
 }
@@ -430,7 +442,7 @@ pub async fn command(
     ctx: &Context,
     msg: &Message,
     stream: &mut Stream<'_>,
-    groups: &[(&'static CommandGroup, Map)],
+    groups: &[(&'static CommandGroup, Arc<Map>)],
     config: &Configuration,
     help_was_set: Option<&[&'static str]>,
 ) -> Result<Invoke, ParseError> {
@@ -453,7 +465,7 @@ pub async fn command(
     let mut is_prefixless = false;
 
     for (group, map) in groups {
-        match map {
+        match &**map {
             // Includes [group] itself.
             Map::WithPrefixes(map) => {
                 let res = handle_group(stream, ctx, msg, config, map).await;
@@ -18,18 +18,14 @@ pub use self::check::*;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[non_exhaustive]
+#[derive(Default)]
 pub enum OnlyIn {
     Dm,
     Guild,
+    #[default]
     None,
 }
 
-impl Default for OnlyIn {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 #[derive(Debug, Default, PartialEq)]
 pub struct CommandOptions {
     /// A set of checks to be called prior to executing the command. The checks will short-circuit
@@ -46,6 +42,9 @@ pub struct CommandOptions {
     ///
     /// [global delimiters]: super::Configuration::delimiters
     pub delimiters: &'static [&'static str],
+    /// Whether the entire remainder of the message after the command name is treated as a
+    /// single, trailing argument rather than being split by delimiters.
+    pub rest: bool,
     /// Command usage schema, used by other commands.
     pub usage: Option<&'static str>,
     /// Example arguments, used by other commands.
@@ -62,6 +61,8 @@ pub struct CommandOptions {
     pub help_available: bool,
     /// Whether the command can only be used in dms or guilds; or both.
     pub only_in: OnlyIn,
+    /// Whether the command can only be used in NSFW channels or not.
+    pub nsfw_only: bool,
     /// Whether the command can only be used by owners or not.
     pub owners_only: bool,
     /// Whether the command treats owners as normal users.
@@ -221,6 +222,7 @@ pub struct HelpOptions {
 pub struct GroupOptions {
     pub prefixes: &'static [&'static str],
     pub only_in: OnlyIn,
+    pub nsfw_only: bool,
     pub owners_only: bool,
     pub owner_privilege: bool,
     pub help_available: bool,
@@ -181,6 +181,10 @@ impl ShardManager {
     ///
     /// This will communicate shard boots with the [`ShardQueuer`] so that they are properly
     /// queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Gateway`] if a shard boot could not be communicated to the queuer.
     #[instrument(skip(self))]
     pub fn initialize(&self) -> Result<()> {
         let shard_index = self.shard_index.load(Ordering::Relaxed);
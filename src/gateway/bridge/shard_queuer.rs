@@ -142,7 +142,7 @@ impl ShardQueuer {
             return;
         }
 
-        let to_sleep = duration - elapsed;
+        let to_sleep = duration.saturating_sub(elapsed);
 
         sleep(to_sleep).await;
     }
@@ -94,6 +94,11 @@ impl ShardRunner {
     /// 6. Go back to 1.
     ///
     /// [`ShardManager`]: super::ShardManager
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shard fails to communicate with the gateway while handling
+    /// incoming events and requests.
     #[instrument(skip(self))]
     pub async fn run(&mut self) -> Result<()> {
         info!("[ShardRunner {:?}] Running", self.shard.shard_info());
@@ -156,7 +161,7 @@ impl ShardRunner {
                                     return self.request_restart().await;
                                 }
                             },
-                        };
+                        }
                     }
                 },
                 None => {},
@@ -243,7 +248,7 @@ impl ShardRunner {
                     );
                     break;
                 },
-                _ => continue,
+                _ => (),
             }
         }
 
@@ -354,13 +359,13 @@ impl ShardRunner {
     #[instrument(skip(self))]
     async fn recv(&mut self) -> Result<bool> {
         loop {
-            match self.runner_rx.try_next() {
-                Ok(Some(value)) => {
+            match self.runner_rx.try_recv() {
+                Ok(value) => {
                     if !self.handle_rx_value(value).await {
                         return Ok(false);
                     }
                 },
-                Ok(None) => {
+                Err(why) if why.is_closed() => {
                     warn!(
                         "[ShardRunner {:?}] Sending half DC; restarting",
                         self.shard.shard_info(),
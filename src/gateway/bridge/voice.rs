@@ -1,3 +1,24 @@
+//! The plugin interface serenity exposes for voice support.
+//!
+//! Serenity itself does not open voice websocket or UDP connections; it forwards the gateway
+//! events a voice plugin needs and lets the plugin drive the connection. This split is
+//! deliberate: serenity's built-in voice support was removed in favour of this interface, and an
+//! in-tree connection stack is not planned. Joining a voice channel produces a pair of events:
+//! `VOICE_STATE_UPDATE` carries the session ID, and `VOICE_SERVER_UPDATE` carries the endpoint
+//! and token. Both are routed to the registered [`VoiceGatewayManager`], which matches them up by
+//! guild to form a voice session, then performs IP discovery, encryption and audio transmission
+//! itself.
+//!
+//! Audio receive works through the same interface and is likewise out of scope for serenity
+//! itself: nothing else is needed from the main gateway, as speaker identification happens over
+//! the voice websocket. Plugins decrypt incoming RTP packets and map their SSRCs to users via the
+//! voice gateway's `Speaking` payload, which the `voice_model` feature provides a model for.
+//!
+//! [Songbird] is the most complete implementation of this interface, including opt-in receive
+//! support.
+//!
+//! [Songbird]: https://github.com/serenity-rs/songbird
+
 use async_trait::async_trait;
 use futures::channel::mpsc::UnboundedSender as Sender;
 
@@ -655,6 +655,10 @@ impl Shard {
     /// [`Event::GuildMembersChunk`]: crate::model::event::Event::GuildMembersChunk
     /// [`Guild`]: crate::model::guild::Guild
     /// [`Member`]: crate::model::guild::Member
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the request could not be sent over the websocket.
     #[instrument(skip(self))]
     pub async fn chunk_guild(
         &mut self,
@@ -674,6 +678,10 @@ impl Shard {
     /// Sets the shard as going into identifying stage, which sets:
     /// - the time that the last heartbeat sent as being now
     /// - the `stage` to [`ConnectionStage::Identifying`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the IDENTIFY could not be sent over the websocket.
     #[instrument(skip(self))]
     pub async fn identify(&mut self) -> Result<()> {
         self.client
@@ -689,6 +697,10 @@ impl Shard {
     /// Initializes a new WebSocket client.
     ///
     /// This will set the stage of the shard before and after instantiation of the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the websocket connection could not be established.
     #[instrument(skip(self))]
     pub async fn initialize(&mut self) -> Result<WsClient> {
         debug!("[{:?}] Initializing.", self.shard_info);
@@ -720,6 +732,12 @@ impl Shard {
         self.seq = 0;
     }
 
+    /// Attempts to resume the session using the shard's stored session id and sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::NoSessionId`] if no session is stored, or an error if
+    /// re-establishing the websocket connection or sending the RESUME fails.
     #[instrument(skip(self))]
     pub async fn resume(&mut self) -> Result<()> {
         debug!("[{:?}] Attempting to resume", self.shard_info);
@@ -735,6 +753,11 @@ impl Shard {
         }
     }
 
+    /// Resets the shard's state and initializes a new websocket connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the websocket connection could not be established.
     #[instrument(skip(self))]
     pub async fn reconnect(&mut self) -> Result<()> {
         info!("[{:?}] Attempting to reconnect", self.shard_info());
@@ -745,6 +768,11 @@ impl Shard {
         Ok(())
     }
 
+    /// Sends the shard's current presence to the gateway.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the presence could not be sent over the websocket.
     #[instrument(skip(self))]
     pub async fn update_presence(&mut self) -> Result<()> {
         self.client.send_presence_update(&self.shard_info, &self.presence).await
@@ -54,7 +54,8 @@ struct ChunkGuildMessage<'a> {
     presences: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_ids: Option<Vec<UserId>>,
-    nonce: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<&'a str>,
 }
 
 #[derive(Serialize)]
@@ -206,12 +207,17 @@ impl WsClient {
                 limit: limit.unwrap_or(0),
                 presences,
                 user_ids,
-                nonce: nonce.unwrap_or(""),
+                nonce,
             }),
         })
         .await
     }
 
+    /// Sends a heartbeat with the given sequence number over the websocket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the message could not be sent.
     #[instrument(skip(self))]
     pub async fn send_heartbeat(&mut self, shard_info: &ShardInfo, seq: Option<u64>) -> Result<()> {
         trace!("[{:?}] Sending heartbeat d: {:?}", shard_info, seq);
@@ -223,6 +229,11 @@ impl WsClient {
         .await
     }
 
+    /// Identifies a new session with the gateway.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the message could not be sent.
     #[instrument(skip(self, token))]
     pub async fn send_identify(
         &mut self,
@@ -261,6 +272,11 @@ impl WsClient {
         self.send_json(&msg).await
     }
 
+    /// Sends a presence update for the current session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the message could not be sent.
     #[instrument(skip(self))]
     pub async fn send_presence_update(
         &mut self,
@@ -284,6 +300,11 @@ impl WsClient {
         .await
     }
 
+    /// Attempts to resume the given session at the given sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the message could not be sent.
     #[instrument(skip(self, token))]
     pub async fn send_resume(
         &mut self,
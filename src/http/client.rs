@@ -15,6 +15,8 @@ use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
 use tracing::{debug, instrument, trace};
 
+#[cfg(feature = "http_mock")]
+use super::mock::MockTransport;
 use super::multipart::{Multipart, MultipartUpload};
 use super::ratelimiting::Ratelimiter;
 use super::request::Request;
@@ -59,6 +61,8 @@ pub struct HttpBuilder {
     token: SecretString,
     proxy: Option<String>,
     application_id: Option<ApplicationId>,
+    #[cfg(feature = "http_mock")]
+    mock_transport: Option<Arc<MockTransport>>,
 }
 
 impl HttpBuilder {
@@ -72,6 +76,8 @@ impl HttpBuilder {
             token: SecretString::new(parse_token(token)),
             proxy: None,
             application_id: None,
+            #[cfg(feature = "http_mock")]
+            mock_transport: None,
         }
     }
 
@@ -130,6 +136,14 @@ impl HttpBuilder {
         self
     }
 
+    /// Answers every request from the given [`MockTransport`] instead of sending it over the
+    /// network. Intended for unit tests; see the [`MockTransport`] docs for an example.
+    #[cfg(feature = "http_mock")]
+    pub fn mock_transport(mut self, transport: Arc<MockTransport>) -> Self {
+        self.mock_transport = Some(transport);
+        self
+    }
+
     /// Use the given configuration to build the `Http` client.
     #[must_use]
     pub fn build(self) -> Http {
@@ -151,6 +165,8 @@ impl HttpBuilder {
             proxy: self.proxy,
             token: self.token,
             application_id,
+            #[cfg(feature = "http_mock")]
+            mock_transport: self.mock_transport,
         }
     }
 }
@@ -189,6 +205,8 @@ pub struct Http {
     pub proxy: Option<String>,
     token: SecretString,
     application_id: AtomicU64,
+    #[cfg(feature = "http_mock")]
+    mock_transport: Option<Arc<MockTransport>>,
 }
 
 impl Http {
@@ -274,10 +292,10 @@ impl Http {
     }
 
     /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last X number of
-    /// days.
+    /// seconds.
     ///
-    /// Passing a `delete_message_days` of `0` is equivalent to not removing any messages. Up to
-    /// `7` days' worth of messages may be deleted.
+    /// Passing a `delete_message_seconds` of `0` is equivalent to not removing any messages. Up
+    /// to `604800` seconds' (7 days') worth of messages may be deleted.
     ///
     /// **Note**: Requires that you have the [Ban Members] permission.
     ///
@@ -286,7 +304,7 @@ impl Http {
         &self,
         guild_id: GuildId,
         user_id: UserId,
-        delete_message_days: u8,
+        delete_message_seconds: u32,
         reason: Option<&str>,
     ) -> Result<()> {
         self.wind(204, Request {
@@ -298,7 +316,7 @@ impl Http {
                 guild_id,
                 user_id,
             },
-            params: Some(vec![("delete_message_days", delete_message_days.to_string())]),
+            params: Some(vec![("delete_message_seconds", delete_message_seconds.to_string())]),
         })
         .await
     }
@@ -814,7 +832,7 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Creates a Guild Scheduled Event.
@@ -844,6 +862,32 @@ impl Http {
         .await
     }
 
+    /// Creates a soundboard sound in a guild.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Post,
+            route: Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Creates a sticker.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -1326,6 +1370,34 @@ impl Http {
         .await
     }
 
+    /// Deletes a soundboard sound from a guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub async fn delete_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundboardSoundId,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Delete,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Deletes a sticker from a server.
     ///
     /// See [`GuildId::delete_sticker`] for permissions requirements.
@@ -1683,19 +1755,78 @@ impl Http {
         .await
     }
 
+    /// Edits the incident actions of a guild, pausing invites and/or direct messages for up to 24
+    /// hours. Requires the [Manage Guild] and [Administrator] permissions.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Administrator]: Permissions::ADMINISTRATOR
+    pub async fn edit_guild_incident_actions(
+        &self,
+        guild_id: GuildId,
+        invites_disabled_until: Option<Timestamp>,
+        dms_disabled_until: Option<Timestamp>,
+    ) -> Result<IncidentsData> {
+        #[derive(Serialize)]
+        struct IncidentActions {
+            invites_disabled_until: Option<Timestamp>,
+            dms_disabled_until: Option<Timestamp>,
+        }
+
+        let body = to_vec(&IncidentActions {
+            invites_disabled_until,
+            dms_disabled_until,
+        })?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::GuildIncidentActions {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
+    /// Edits a guild's membership screening form.
+    pub async fn edit_guild_member_verification(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<GuildMemberVerification> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildMemberVerification {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Edits the MFA level of a guild. Requires guild ownership.
     pub async fn edit_guild_mfa_level(
         &self,
         guild_id: GuildId,
-        value: &Value,
+        mfa_level: MfaLevel,
         audit_log_reason: Option<&str>,
     ) -> Result<MfaLevel> {
-        #[derive(Deserialize)]
+        #[derive(Deserialize, Serialize)]
         struct GuildMfaLevel {
             level: MfaLevel,
         }
 
-        let body = to_vec(value)?;
+        let body = to_vec(&GuildMfaLevel {
+            level: mfa_level,
+        })?;
 
         self.fire(Request {
             body: Some(body),
@@ -1755,6 +1886,28 @@ impl Http {
         .await
     }
 
+    /// Edits a guild's onboarding flow.
+    pub async fn edit_guild_onboarding(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<GuildOnboarding> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Put,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Does specific actions to a member.
     pub async fn edit_member(
         &self,
@@ -1783,7 +1936,7 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value::<Member>(value).map_err(From::from)
+        from_value::<Member>(value)
     }
 
     /// Edits a message by Id.
@@ -2008,7 +2161,40 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
+    }
+
+    /// Changes the positions of multiple roles in a guild at once.
+    pub async fn edit_role_positions(
+        &self,
+        guild_id: GuildId,
+        value: &Value,
+        audit_log_reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let body = to_vec(value)?;
+
+        let mut value: Value = self
+            .fire(Request {
+                body: Some(body),
+                multipart: None,
+                headers: audit_log_reason.map(reason_into_header),
+                method: LightMethod::Patch,
+                route: Route::GuildRoles {
+                    guild_id,
+                },
+                params: None,
+            })
+            .await?;
+
+        if let Some(array) = value.as_array_mut() {
+            for role in array {
+                if let Some(map) = role.as_object_mut() {
+                    map.insert("guild_id".to_string(), guild_id.get().into());
+                }
+            }
+        }
+
+        from_value(value)
     }
 
     /// Changes the position of a role in a guild.
@@ -2046,7 +2232,7 @@ impl Http {
             }
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Modifies a scheduled event.
@@ -2076,6 +2262,37 @@ impl Http {
         .await
     }
 
+    /// Changes a soundboard sound in a guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub async fn edit_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundboardSoundId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Changes a sticker in a guild.
     ///
     /// See [`GuildId::edit_sticker`] for permissions requirements.
@@ -2106,7 +2323,7 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Edits a thread channel in the [`GuildChannel`] given its Id.
@@ -2572,6 +2789,22 @@ impl Http {
         .await
     }
 
+    /// Gets the ban entry of a specific banned user in a guild.
+    pub async fn get_ban(&self, guild_id: GuildId, user_id: UserId) -> Result<Ban> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildBan {
+                guild_id,
+                user_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets all audit logs in a specific guild.
     pub async fn get_audit_logs(
         &self,
@@ -3038,6 +3271,19 @@ impl Http {
         .await
     }
 
+    /// Gets the default soundboard sounds available to all users.
+    pub async fn get_default_soundboard_sounds(&self) -> Result<Vec<SoundboardSound>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::SoundboardDefaultSounds,
+            params: None,
+        })
+        .await
+    }
+
     /// Gets all emojis of a guild.
     pub async fn get_emojis(&self, guild_id: GuildId) -> Result<Vec<Emoji>> {
         self.fire(Request {
@@ -3272,6 +3518,21 @@ impl Http {
         .await
     }
 
+    /// Gets a guild's onboarding flow.
+    pub async fn get_guild_onboarding(&self, guild_id: GuildId) -> Result<GuildOnboarding> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets a guild preview.
     pub async fn get_guild_preview(&self, guild_id: GuildId) -> Result<GuildPreview> {
         self.fire(Request {
@@ -3302,6 +3563,24 @@ impl Http {
         .await
     }
 
+    /// Gets a guild's membership screening form.
+    pub async fn get_guild_member_verification(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<GuildMemberVerification> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildMemberVerification {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets integrations that a guild has.
     pub async fn get_guild_integrations(&self, guild_id: GuildId) -> Result<Vec<Integration>> {
         self.fire(Request {
@@ -3333,13 +3612,11 @@ impl Http {
     }
 
     /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: GuildId) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
-
-        self.fire::<GuildVanityUrl>(Request {
+    ///
+    /// Returns an [`Error::Http`] with a 403 status code if the guild lacks the `VANITY_URL`
+    /// feature.
+    pub async fn get_guild_vanity_url(&self, guild_id: GuildId) -> Result<GuildVanityUrl> {
+        self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
@@ -3350,7 +3627,6 @@ impl Http {
             params: None,
         })
         .await
-        .map(|x| x.code)
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the user to offset the
@@ -3394,7 +3670,7 @@ impl Http {
             }
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Gets the amount of users that can be pruned.
@@ -3451,7 +3727,7 @@ impl Http {
             }
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Gets a scheduled event by Id.
@@ -3540,16 +3816,73 @@ impl Http {
             }
         }
 
+        let mut value: Value = self
+            .fire(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                method: LightMethod::Get,
+                route: Route::GuildScheduledEventUsers {
+                    guild_id,
+                    event_id,
+                },
+                params: Some(params),
+            })
+            .await?;
+
+        if let Some(values) = value.as_array_mut() {
+            for value in values {
+                if let Some(element) = value.as_object_mut() {
+                    if let Some(member) = element.get_mut("member").and_then(Value::as_object_mut) {
+                        member.insert("guild_id".to_string(), guild_id.get().into());
+                    }
+                }
+            }
+        }
+
+        from_value(value)
+    }
+
+    /// Retrieves a list of soundboard sounds in a [`Guild`].
+    pub async fn get_guild_soundboard_sounds(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<SoundboardSound>> {
+        #[derive(Deserialize)]
+        struct SoundboardSounds {
+            items: Vec<SoundboardSound>,
+        }
+
+        self.fire::<SoundboardSounds>(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+        .map(|s| s.items)
+    }
+
+    /// Retrieves a single soundboard sound in a [`Guild`].
+    pub async fn get_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundboardSoundId,
+    ) -> Result<SoundboardSound> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
             method: LightMethod::Get,
-            route: Route::GuildScheduledEventUsers {
+            route: Route::GuildSoundboardSound {
                 guild_id,
-                event_id,
+                sound_id,
             },
-            params: Some(params),
+            params: None,
         })
         .await
     }
@@ -3577,7 +3910,7 @@ impl Http {
             }
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Retrieves a single sticker in a [`Guild`].
@@ -3604,7 +3937,7 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
@@ -3694,6 +4027,37 @@ impl Http {
         .await
     }
 
+    /// Gets a paged list of guilds the current user is in, with approximate member and presence
+    /// counts filled in on each [`GuildInfo`].
+    ///
+    /// Refer to [`Self::get_guilds`] for the pagination behaviour.
+    pub async fn get_guilds_with_counts(
+        &self,
+        target: Option<GuildPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<GuildInfo>> {
+        let mut params = vec![("with_counts", true.to_string())];
+        if let Some(limit) = limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(target) = target {
+            match target {
+                GuildPagination::After(id) => params.push(("after", id.to_string())),
+                GuildPagination::Before(id) => params.push(("before", id.to_string())),
+            }
+        }
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::UserMeGuilds,
+            params: Some(params),
+        })
+        .await
+    }
+
     /// Returns a guild [`Member`] object for the current user.
     ///
     /// # Authorization
@@ -3741,15 +4105,15 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Gets information about a specific invite.
     ///
     /// # Arguments
     /// * `code` - The invite code.
-    /// * `member_counts` - Whether to include information about the current number
-    /// of members in the server that the invite belongs to.
+    /// * `member_counts` - Whether to include information about the current number of members in
+    ///   the server that the invite belongs to.
     /// * `expiration` - Whether to include information about when the invite expires.
     /// * `event_id` - An optional server event ID to include with the invite.
     ///
@@ -3806,7 +4170,7 @@ impl Http {
             map.insert("guild_id".to_string(), guild_id.get().into());
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Gets a message by an Id, bots only.
@@ -3923,6 +4287,38 @@ impl Http {
         .await
     }
 
+    /// Gets all users that super-reacted to a message with an emoji.
+    ///
+    /// This is the same as [`Self::get_reaction_users`], except that only super reactions are
+    /// listed.
+    pub async fn get_burst_reaction_users(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        reaction_type: &ReactionType,
+        limit: u8,
+        after: Option<u64>,
+    ) -> Result<Vec<User>> {
+        // Reaction type 1 is a super reaction, 0 a normal one.
+        let mut params = vec![("limit", limit.to_string()), ("type", "1".to_string())];
+        if let Some(after) = after {
+            params.push(("after", after.to_string()));
+        }
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::ChannelMessageReactionEmoji {
+                channel_id,
+                message_id,
+                reaction: &reaction_type.as_data(),
+            },
+            params: Some(params),
+        })
+        .await
+    }
+
     /// Gets a sticker.
     pub async fn get_sticker(&self, sticker_id: StickerId) -> Result<Sticker> {
         self.fire(Request {
@@ -4226,6 +4622,33 @@ impl Http {
         self.fire(request).await
     }
 
+    /// Plays a soundboard sound in the given voice channel.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, as well as [Use External
+    /// Sounds] for sounds from other guilds, and the current user must be connected to the voice
+    /// channel without being muted or deafened.
+    ///
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    /// [Use External Sounds]: Permissions::USE_EXTERNAL_SOUNDS
+    pub async fn send_soundboard_sound(
+        &self,
+        channel_id: ChannelId,
+        map: &impl serde::Serialize,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::ChannelSendSoundboardSound {
+                channel_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Pins a message in a channel.
     pub async fn pin_message(
         &self,
@@ -4327,7 +4750,7 @@ impl Http {
             }
         }
 
-        from_value(value).map_err(From::from)
+        from_value(value)
     }
 
     /// Starts removing some members from a guild based on the last time they've been online.
@@ -4507,12 +4930,14 @@ impl Http {
     #[instrument]
     pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
         let method = req.method.reqwest_method();
-        let response = if let Some(ratelimiter) = &self.ratelimiter {
-            ratelimiter.perform(req).await?
-        } else {
-            let request = req.build(&self.client, self.token(), self.proxy.as_deref())?.build()?;
-            self.client.execute(request).await?
+
+        #[cfg(feature = "http_mock")]
+        let response = match &self.mock_transport {
+            Some(mock) => mock.respond(&req),
+            None => self.dispatch(req).await?,
         };
+        #[cfg(not(feature = "http_mock"))]
+        let response = self.dispatch(req).await?;
 
         if response.status().is_success() {
             Ok(response)
@@ -4523,6 +4948,16 @@ impl Http {
         }
     }
 
+    /// Sends a request over the network, ratelimiting it if necessary.
+    async fn dispatch(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        if let Some(ratelimiter) = &self.ratelimiter {
+            ratelimiter.perform(req).await
+        } else {
+            let request = req.build(&self.client, self.token(), self.proxy.as_deref())?.build()?;
+            Ok(self.client.execute(request).await?)
+        }
+    }
+
     /// Performs a request and then verifies that the response status code is equal to the expected
     /// value.
     ///
@@ -6,6 +6,7 @@ use reqwest::{Error as ReqwestError, Method, Response, StatusCode};
 use serde::de::{Deserialize, Deserializer, Error as _};
 use url::ParseError as UrlError;
 
+use crate::http::ratelimiting::RatelimitedError;
 use crate::internal::prelude::*;
 use crate::json::*;
 
@@ -80,6 +81,9 @@ pub enum HttpError {
     InvalidPort,
     /// When an application id was expected but missing.
     ApplicationIdMissing,
+    /// When a request was ratelimited and the ratelimiter is configured to return instead of
+    /// sleeping until the ratelimit expires.
+    Ratelimited(RatelimitedError),
 }
 
 impl HttpError {
@@ -168,6 +172,9 @@ impl fmt::Display for HttpError {
             Self::InvalidScheme => f.write_str("Invalid Url scheme."),
             Self::InvalidPort => f.write_str("Invalid port."),
             Self::ApplicationIdMissing => f.write_str("Application id was expected but missing."),
+            Self::Ratelimited(e) => {
+                write!(f, "Request was ratelimited for {:?}.", e.retry_after)
+            },
         }
     }
 }
@@ -0,0 +1,131 @@
+//! A programmable mock transport for unit testing code that calls the Discord API.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Response as ReqwestResponse;
+
+use super::request::Request;
+use super::LightMethod;
+
+/// A table of canned responses for [`Http`], keyed by method and route path.
+///
+/// Once registered via [`HttpBuilder::mock_transport`], every request performed by the [`Http`]
+/// instance is answered from this table instead of being sent over the network, enabling unit
+/// tests of command handlers without hitting Discord. Requests with no registered response panic
+/// with the offending method and path.
+///
+/// Paths are relative to the API base, e.g. `/users/@me`. Registered status codes are handled
+/// exactly like real ones: non-success codes surface as [`Error::Http`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "http_mock")]
+/// # async fn run() {
+/// use std::sync::Arc;
+///
+/// use serenity::http::{HttpBuilder, LightMethod, MockTransport};
+///
+/// let transport = Arc::new(MockTransport::new());
+/// transport.insert(
+///     LightMethod::Get,
+///     "/users/@me",
+///     200,
+///     r#"{"id": "1", "username": "testbot", "discriminator": "0000"}"#,
+/// );
+///
+/// let http = HttpBuilder::new("").mock_transport(Arc::clone(&transport)).build();
+/// let user = http.get_current_user().await.unwrap();
+/// assert_eq!(user.name, "testbot");
+/// # }
+/// ```
+///
+/// [`Error::Http`]: crate::Error::Http
+/// [`Http`]: super::Http
+/// [`HttpBuilder::mock_transport`]: super::HttpBuilder::mock_transport
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    #[allow(clippy::type_complexity)]
+    responses: Mutex<HashMap<(LightMethod, String), (u16, Vec<u8>)>>,
+}
+
+impl MockTransport {
+    /// Creates an empty response table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a canned response for requests with the given method and path.
+    ///
+    /// The path is relative to the API base, e.g. `/channels/381880193700069377/messages`.
+    /// Registering a second response for the same method and path replaces the first.
+    pub fn insert(
+        &self,
+        method: LightMethod,
+        path: impl Into<String>,
+        status: u16,
+        body: impl Into<Vec<u8>>,
+    ) {
+        self.responses
+            .lock()
+            .expect("poisoned mock response table")
+            .insert((method, path.into()), (status, body.into()));
+    }
+
+    /// Answers a request from the response table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no response is registered for the request's method and path.
+    pub(super) fn respond(&self, req: &Request<'_>) -> ReqwestResponse {
+        let url = req.route.path();
+        let path = url.strip_prefix(api!("")).unwrap_or(&url);
+
+        let responses = self.responses.lock().expect("poisoned mock response table");
+        let Some((status, body)) = responses.get(&(req.method, path.to_string())) else {
+            panic!("no canned response registered for {:?} {path}", req.method);
+        };
+
+        let response = http_crate::Response::builder()
+            .status(*status)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone())
+            .expect("canned response must be valid");
+        ReqwestResponse::from(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::MockTransport;
+    use crate::http::{HttpBuilder, LightMethod};
+
+    #[tokio::test]
+    async fn canned_responses() {
+        let transport = Arc::new(MockTransport::new());
+        transport.insert(
+            LightMethod::Get,
+            "/users/@me",
+            200,
+            r#"{"id": "1", "username": "testbot", "discriminator": "0000"}"#,
+        );
+
+        let http = HttpBuilder::new("").mock_transport(Arc::clone(&transport)).build();
+        let user = http.get_current_user().await.unwrap();
+        assert_eq!(user.name, "testbot");
+
+        // Registered error statuses surface like real API errors.
+        transport.insert(
+            LightMethod::Get,
+            "/users/@me",
+            403,
+            r#"{"code": 50001, "message": "Missing Access"}"#,
+        );
+        assert!(http.get_current_user().await.is_err());
+    }
+}
@@ -21,6 +21,8 @@
 
 mod client;
 mod error;
+#[cfg(feature = "http_mock")]
+mod mock;
 mod multipart;
 mod ratelimiting;
 mod request;
@@ -34,6 +36,8 @@ pub use reqwest::StatusCode;
 
 pub use self::client::*;
 pub use self::error::*;
+#[cfg(feature = "http_mock")]
+pub use self::mock::*;
 pub use self::multipart::*;
 pub use self::ratelimiting::*;
 pub use self::request::*;
@@ -64,6 +64,19 @@ pub struct RatelimitInfo {
     pub global: bool,
 }
 
+/// Error returned by the [`Ratelimiter`] instead of sleeping, if configured to do so with
+/// [`Ratelimiter::set_wait_for_ratelimits`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct RatelimitedError {
+    /// How long the request has to wait before it can be retried.
+    pub retry_after: Duration,
+    /// Whether the global ratelimit was hit.
+    pub global: bool,
+    /// The ratelimiting bucket of the route that was ratelimited.
+    pub bucket: RatelimitingBucket,
+}
+
 /// Ratelimiter for requests to the Discord API.
 ///
 /// This keeps track of ratelimit data for known routes through the [`Ratelimit`] implementation
@@ -89,6 +102,7 @@ pub struct Ratelimiter {
     routes: Arc<RwLock<HashMap<RatelimitingBucket, Arc<Mutex<Ratelimit>>>>>,
     token: SecretString,
     absolute_ratelimits: bool,
+    wait_for_ratelimits: bool,
     ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
 }
 
@@ -100,6 +114,7 @@ impl fmt::Debug for Ratelimiter {
             .field("routes", &self.routes)
             .field("token", &self.token)
             .field("absolute_ratelimits", &self.absolute_ratelimits)
+            .field("wait_for_ratelimits", &self.wait_for_ratelimits)
             .field("ratelimit_callback", &"Fn(RatelimitInfo)")
             .finish()
     }
@@ -122,6 +137,7 @@ impl Ratelimiter {
             token: SecretString::new(token),
             ratelimit_callback: Box::new(|_| {}),
             absolute_ratelimits: false,
+            wait_for_ratelimits: true,
         }
     }
 
@@ -138,6 +154,13 @@ impl Ratelimiter {
         self.absolute_ratelimits = absolute_ratelimits;
     }
 
+    /// Sets whether the ratelimiter should sleep until ratelimits expire, which is the default.
+    /// If disabled, a ratelimited request fails with [`HttpError::Ratelimited`] instead, leaving
+    /// the decision of how to back off to the caller.
+    pub fn set_wait_for_ratelimits(&mut self, wait_for_ratelimits: bool) {
+        self.wait_for_ratelimits = wait_for_ratelimits;
+    }
+
     /// The routes mutex is a HashMap of each [`RatelimitingBucket`] and their respective ratelimit
     /// information.
     ///
@@ -177,7 +200,9 @@ impl Ratelimiter {
 
     /// # Errors
     ///
-    /// Only error kind that may be returned is [`Error::Http`].
+    /// Only error kind that may be returned is [`Error::Http`]. In particular, if configured with
+    /// [`Self::set_wait_for_ratelimits`] to not wait, a ratelimited request returns
+    /// [`HttpError::Ratelimited`].
     #[instrument]
     pub async fn perform(&self, req: Request<'_>) -> Result<Response> {
         loop {
@@ -194,7 +219,11 @@ impl Ratelimiter {
             let bucket =
                 Arc::clone(self.routes.write().await.entry(ratelimiting_bucket).or_default());
 
-            bucket.lock().await.pre_hook(&req, &self.ratelimit_callback).await;
+            bucket
+                .lock()
+                .await
+                .pre_hook(&req, &self.ratelimit_callback, self.wait_for_ratelimits)
+                .await?;
 
             let request = req.clone().build(&self.client, self.token.expose_secret(), None)?;
             let response = self.client.execute(request.build()?).await?;
@@ -233,6 +262,13 @@ impl Ratelimiter {
                             path: req.route.path().to_string(),
                             global: true,
                         });
+                        if !self.wait_for_ratelimits {
+                            return Err(Error::Http(HttpError::Ratelimited(RatelimitedError {
+                                retry_after: Duration::from_secs_f64(retry_after),
+                                global: true,
+                                bucket: ratelimiting_bucket,
+                            })));
+                        }
                         sleep(Duration::from_secs_f64(retry_after)).await;
 
                         true
@@ -244,11 +280,17 @@ impl Ratelimiter {
                 bucket
                     .lock()
                     .await
-                    .post_hook(&response, &req, &self.ratelimit_callback, self.absolute_ratelimits)
+                    .post_hook(
+                        &response,
+                        &req,
+                        &self.ratelimit_callback,
+                        self.absolute_ratelimits,
+                        self.wait_for_ratelimits,
+                    )
                     .await
             };
 
-            if !redo.unwrap_or(true) {
+            if !redo? {
                 return Ok(response);
             }
         }
@@ -277,20 +319,28 @@ pub struct Ratelimit {
 }
 
 impl Ratelimit {
+    /// Checks the bucket before a request is made, pre-emptively sleeping if the route's
+    /// ratelimit has been exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::Ratelimited`] if the ratelimit has been exhausted and
+    /// `wait_for_ratelimits` is disabled.
     #[instrument(skip(ratelimit_callback))]
     pub async fn pre_hook(
         &mut self,
         req: &Request<'_>,
         ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
-    ) {
+        wait_for_ratelimits: bool,
+    ) -> Result<()> {
         if self.limit() == 0 {
-            return;
+            return Ok(());
         }
 
         let Some(reset) = self.reset else {
             // We're probably in the past.
             self.remaining = self.limit;
-            return;
+            return Ok(());
         };
 
         let Ok(delay) = reset.duration_since(SystemTime::now()) else {
@@ -298,7 +348,7 @@ impl Ratelimit {
             if self.remaining() != 0 {
                 self.remaining -= 1;
             }
-            return;
+            return Ok(());
         };
 
         if self.remaining() == 0 {
@@ -315,14 +365,31 @@ impl Ratelimit {
                 global: false,
             });
 
+            if !wait_for_ratelimits {
+                return Err(Error::Http(HttpError::Ratelimited(RatelimitedError {
+                    retry_after: delay,
+                    global: false,
+                    bucket: req.route.ratelimiting_bucket(),
+                })));
+            }
+
             sleep(delay).await;
 
-            return;
+            return Ok(());
         }
 
         self.remaining -= 1;
+        Ok(())
     }
 
+    /// Updates the bucket from the ratelimit headers of a response, pre-emptively sleeping if
+    /// the route's ratelimit was exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a ratelimit header could not be parsed, or
+    /// [`HttpError::Ratelimited`] if the route was ratelimited and `wait_for_ratelimits` is
+    /// disabled.
     #[instrument(skip(ratelimit_callback))]
     pub async fn post_hook(
         &mut self,
@@ -330,6 +397,7 @@ impl Ratelimit {
         req: &Request<'_>,
         ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
         absolute_ratelimits: bool,
+        wait_for_ratelimits: bool,
     ) -> Result<bool> {
         if let Some(limit) = parse_header(response.headers(), "x-ratelimit-limit")? {
             self.limit = limit;
@@ -371,6 +439,14 @@ impl Ratelimit {
                 global: false,
             });
 
+            if !wait_for_ratelimits {
+                return Err(Error::Http(HttpError::Ratelimited(RatelimitedError {
+                    retry_after: Duration::from_secs_f64(retry_after),
+                    global: false,
+                    bucket: req.route.ratelimiting_bucket(),
+                })));
+            }
+
             sleep(Duration::from_secs_f64(retry_after)).await;
 
             true
@@ -63,6 +63,12 @@ impl<'a> Request<'a> {
         self
     }
 
+    /// Builds a reqwest request from this request's route, headers, body and parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Url`] if the route and parameters do not form a valid URL, or an
+    /// [`HttpError::InvalidHeader`] if a header value is invalid.
     #[instrument(skip(token))]
     pub fn build(
         self,
@@ -80,7 +86,7 @@ impl<'a> Request<'a> {
         if let Some(params) = self.params {
             path += "?";
             for (param, value) in params {
-                write!(path, "&{param}={value}").unwrap();
+                write!(path, "&{param}={value}").expect("writing to a String cannot fail");
             }
         }
 
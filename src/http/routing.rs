@@ -62,7 +62,12 @@ macro_rules! routes {
                 // To avoid adding a lifetime on RatelimitingBucket and causing lifetime infection,
                 // we transmute the Discriminant<Route<'a>> to Discriminant<Route<'static>>.
                 // SAFETY: std::mem::discriminant erases lifetimes.
-                let discriminant = unsafe { std::mem::transmute(std::mem::discriminant(self)) };
+                let discriminant = unsafe {
+                    std::mem::transmute::<
+                        std::mem::Discriminant<Route<'_>>,
+                        std::mem::Discriminant<Route<'static>>,
+                    >(std::mem::discriminant(self))
+                };
 
                 RatelimitingBucket(ratelimiting_kind.map(|r| {
                     let id = match r {
@@ -178,6 +183,10 @@ routes! ('a, {
     api!("/channels/{}/threads/archived/private", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
+    ChannelSendSoundboardSound { channel_id: ChannelId },
+    api!("/channels/{}/send-soundboard-sound", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
     ChannelJoinedPrivateThreads { channel_id: ChannelId },
     api!("/channels/{}/users/@me/threads/archived/private", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
@@ -234,6 +243,10 @@ routes! ('a, {
     api!("/guilds/{}/emojis/{}", guild_id, emoji_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildIncidentActions { guild_id: GuildId },
+    api!("/guilds/{}/incident-actions", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildIntegration { guild_id: GuildId, integration_id: IntegrationId },
     api!("/guilds/{}/integrations/{}", guild_id, integration_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -266,6 +279,10 @@ routes! ('a, {
     api!("/guilds/{}/members/search", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildMemberVerification { guild_id: GuildId },
+    api!("/guilds/{}/member-verification", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildMemberMe { guild_id: GuildId },
     api!("/guilds/{}/members/@me", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -274,6 +291,10 @@ routes! ('a, {
     api!("/guilds/{}/mfa", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildOnboarding { guild_id: GuildId },
+    api!("/guilds/{}/onboarding", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildPrune { guild_id: GuildId },
     api!("/guilds/{}/prune", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -302,6 +323,14 @@ routes! ('a, {
     api!("/guilds/{}/scheduled-events/{}/users", guild_id, event_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildSoundboardSound { guild_id: GuildId, sound_id: SoundboardSoundId },
+    api!("/guilds/{}/soundboard-sounds/{}", guild_id, sound_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildSoundboardSounds { guild_id: GuildId },
+    api!("/guilds/{}/soundboard-sounds", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildSticker { guild_id: GuildId, sticker_id: StickerId },
     api!("/guilds/{}/stickers/{}", guild_id, sticker_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -358,6 +387,10 @@ routes! ('a, {
     status!("/scheduled-maintenances/upcoming.json"),
     None;
 
+    SoundboardDefaultSounds,
+    api!("/soundboard-default-sounds"),
+    Some(RatelimitingKind::Path);
+
     Sticker { sticker_id: StickerId },
     api!("/stickers/{}", sticker_id),
     Some(RatelimitingKind::Path);
@@ -5,7 +5,14 @@
 //!
 //! <https://discord.com/developers/docs/tutorials/upgrading-to-application-commands#adding-an-interactions-endpoint-url>
 //!
-//! See [`Verifier`] for example usage.
+//! See [`Verifier`] for example usage. Once a request has been verified, its body can be
+//! deserialized into an [`Interaction`] with [`crate::json::from_slice`], and the serialized form
+//! of a [`CreateInteractionResponse`] sent back as the HTTP response body. The
+//! `e19_interactions_endpoint` example in the repository shows a complete HTTP server built this
+//! way.
+//!
+//! [`Interaction`]: crate::model::application::Interaction
+//! [`CreateInteractionResponse`]: crate::builder::CreateInteractionResponse
 
 /// Parses a hex string into an array of `[u8]`
 fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
@@ -1,6 +1,6 @@
 //! A set of macros for easily working with internals.
 
-#[cfg(any(feature = "model", feature = "utils"))]
+#[cfg(feature = "model")]
 macro_rules! cdn {
     ($e:expr) => {
         concat!("https://cdn.discordapp.com", $e)
@@ -4,5 +4,7 @@
 
 pub use std::result::Result as StdResult;
 
+// Some minimal feature combinations use Result without naming Error directly.
+#[allow(unused_imports)]
 pub use crate::error::{Error, Result};
 pub use crate::json::{JsonMap, Value};
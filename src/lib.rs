@@ -67,6 +67,7 @@
     clippy::module_name_repetitions,
     clippy::struct_excessive_bools,
     clippy::unused_self,
+    clippy::result_large_err, // boxing the Error variants would break the public API
     // Allowed as they are too pedantic
     clippy::cast_possible_truncation,
     clippy::unreadable_literal,
@@ -77,6 +78,9 @@
     clippy::doc_markdown,
     clippy::cast_lossless,
     clippy::redundant_closure_for_method_calls,
+    clippy::assigning_clones,
+    clippy::used_underscore_items,
+    clippy::struct_field_names,
     // Covered by other lints
     clippy::missing_panics_doc, // clippy::unwrap_used
 )]
@@ -141,7 +145,7 @@ compile_error!(
 /// Not exported:
 /// - [`crate::json`]: it's a general-purpose JSON wrapper, not intrinsic to serenity
 /// - [`crate::framework::standard`]: has many standard_framework-specific items that may collide
-/// with items from the rest of serenity
+///   with items from the rest of serenity
 pub mod all {
     #[cfg(feature = "builder")]
     #[doc(no_inline)]
@@ -172,7 +176,7 @@ pub mod all {
     #[cfg(feature = "utils")]
     #[doc(no_inline)]
     pub use crate::utils::{
-        token::{validate as validate_token, InvalidToken},
+        token::{parse as parse_token, validate as validate_token, InvalidToken, TokenComponents},
         *,
     };
     // #[doc(no_inline)]
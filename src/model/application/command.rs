@@ -12,6 +12,7 @@ use crate::json::Value;
 use crate::model::channel::ChannelType;
 use crate::model::id::{
     ApplicationId,
+    ChannelId,
     CommandId,
     CommandPermissionId,
     CommandVersionId,
@@ -395,6 +396,12 @@ impl CommandPermissionId {
     pub fn to_role_id(self) -> RoleId {
         self.into()
     }
+
+    /// Converts this [`CommandPermissionId`] to [`ChannelId`].
+    #[must_use]
+    pub fn to_channel_id(self) -> ChannelId {
+        self.into()
+    }
 }
 
 impl From<RoleId> for CommandPermissionId {
@@ -420,3 +427,15 @@ impl From<CommandPermissionId> for UserId {
         Self::new(id.get())
     }
 }
+
+impl From<ChannelId> for CommandPermissionId {
+    fn from(id: ChannelId) -> Self {
+        Self::new(id.get())
+    }
+}
+
+impl From<CommandPermissionId> for ChannelId {
+    fn from(id: CommandPermissionId) -> Self {
+        Self::new(id.get())
+    }
+}
@@ -69,14 +69,25 @@ pub struct CommandInteraction {
     #[serde(default)]
     pub user: User,
     /// A continuation token for responding to the interaction.
+    ///
+    /// This is valid for 15 minutes after the interaction was created. The expiry can be
+    /// computed from [`Self::id`]'s creation time.
     pub token: String,
     /// Always `1`.
     pub version: u8,
     /// Permissions the app or bot has within the channel the interaction was sent from.
     pub app_permissions: Option<Permissions>,
-    /// The selected language of the invoking user.
+    /// The selected language of the invoking user, as an [IETF language tag].
+    ///
+    /// Can be matched against the locale keys passed to builder methods such as
+    /// [`CreateCommand::name_localized`] in order to reply in the user's language.
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
+    /// [`CreateCommand::name_localized`]: crate::builder::CreateCommand::name_localized
     pub locale: String,
-    /// The guild's preferred locale.
+    /// The guild's preferred locale, as an [IETF language tag].
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
     pub guild_locale: Option<String>,
 }
 
@@ -326,6 +337,22 @@ impl CommandData {
     }
 
     /// Returns the resolved options from `CommandData::options` and [`CommandData::resolved`].
+    ///
+    /// # Examples
+    ///
+    /// Accessing a user option, with the member data if the command was invoked in a guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::{CommandData, ResolvedOption, ResolvedValue};
+    /// # fn _example(data: &CommandData) {
+    /// if let Some(ResolvedOption {
+    ///     value: ResolvedValue::User(user, member), ..
+    /// }) = data.options().first()
+    /// {
+    ///     println!("user option: {}", user.name);
+    /// }
+    /// # }
+    /// ```
     #[must_use]
     pub fn options(&self) -> Vec<ResolvedOption<'_>> {
         fn resolve_options<'a>(
@@ -417,6 +444,96 @@ impl CommandData {
             _ => None,
         }
     }
+
+    /// Returns the value of the option with the given name, traversing into subcommand and
+    /// subcommand group options.
+    ///
+    /// Returns [`None`] if no option with the given name exists.
+    #[must_use]
+    pub fn get_option(&self, name: &str) -> Option<&CommandDataOptionValue> {
+        fn find_option<'a>(
+            opts: &'a [CommandDataOption],
+            name: &str,
+        ) -> Option<&'a CommandDataOptionValue> {
+            for opt in opts {
+                match &opt.value {
+                    CommandDataOptionValue::SubCommand(opts)
+                    | CommandDataOptionValue::SubCommandGroup(opts) => {
+                        if let Some(value) = find_option(opts, name) {
+                            return Some(value);
+                        }
+                    },
+                    value if opt.name == name => return Some(value),
+                    _ => {},
+                }
+            }
+            None
+        }
+        find_option(&self.options, name)
+    }
+
+    /// Returns the value of the given string option via [`Self::get_option`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::CommandData;
+    /// # fn _example(data: &CommandData) {
+    /// if let Some(query) = data.get_string("query") {
+    ///     println!("searching for {query}");
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.get_option(name)?.as_str()
+    }
+
+    /// Returns the value of the given integer option via [`Self::get_option`].
+    #[must_use]
+    pub fn get_integer(&self, name: &str) -> Option<i64> {
+        self.get_option(name)?.as_i64()
+    }
+
+    /// Returns the value of the given number option via [`Self::get_option`].
+    #[must_use]
+    pub fn get_number(&self, name: &str) -> Option<f64> {
+        self.get_option(name)?.as_f64()
+    }
+
+    /// Returns the value of the given boolean option via [`Self::get_option`].
+    #[must_use]
+    pub fn get_boolean(&self, name: &str) -> Option<bool> {
+        self.get_option(name)?.as_bool()
+    }
+
+    /// Returns the resolved [`User`] of the given user option via [`Self::get_option`] and
+    /// [`Self::resolved`].
+    #[must_use]
+    pub fn get_user(&self, name: &str) -> Option<&User> {
+        self.resolved.users.get(&self.get_option(name)?.as_user_id()?)
+    }
+
+    /// Returns the resolved [`Role`] of the given role option via [`Self::get_option`] and
+    /// [`Self::resolved`].
+    #[must_use]
+    pub fn get_role(&self, name: &str) -> Option<&Role> {
+        self.resolved.roles.get(&self.get_option(name)?.as_role_id()?)
+    }
+
+    /// Returns the resolved [`PartialChannel`] of the given channel option via
+    /// [`Self::get_option`] and [`Self::resolved`].
+    #[must_use]
+    pub fn get_channel(&self, name: &str) -> Option<&PartialChannel> {
+        self.resolved.channels.get(&self.get_option(name)?.as_channel_id()?)
+    }
+
+    /// Returns the resolved [`Attachment`] of the given attachment option via
+    /// [`Self::get_option`] and [`Self::resolved`].
+    #[must_use]
+    pub fn get_attachment(&self, name: &str) -> Option<&Attachment> {
+        self.resolved.attachments.get(&self.get_option(name)?.as_attachment_id()?)
+    }
 }
 
 /// The focused option for autocomplete interactions return by [`CommandData::autocomplete`].
@@ -832,6 +949,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_option_traverses_subcommands() {
+        let data = CommandData {
+            id: CommandId::new(1),
+            name: "search".into(),
+            kind: CommandType::ChatInput,
+            resolved: CommandDataResolved::default(),
+            options: vec![CommandDataOption {
+                name: "web".into(),
+                value: CommandDataOptionValue::SubCommandGroup(vec![CommandDataOption {
+                    name: "images".into(),
+                    value: CommandDataOptionValue::SubCommand(vec![
+                        CommandDataOption {
+                            name: "query".into(),
+                            value: CommandDataOptionValue::String("rust".into()),
+                        },
+                        CommandDataOption {
+                            name: "limit".into(),
+                            value: CommandDataOptionValue::Integer(5),
+                        },
+                    ]),
+                }]),
+            }],
+            guild_id: None,
+            target_id: None,
+        };
+
+        assert_eq!(data.get_string("query"), Some("rust"));
+        assert_eq!(data.get_integer("limit"), Some(5));
+        assert_eq!(data.get_string("missing"), None);
+        assert_eq!(data.get_integer("query"), None);
+    }
+
     #[test]
     fn mixed_options() {
         let value = vec![
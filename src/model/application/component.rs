@@ -21,6 +21,13 @@ enum_number! {
         RoleSelect = 6,
         MentionableSelect = 7,
         ChannelSelect = 8,
+        Section = 9,
+        TextDisplay = 10,
+        Thumbnail = 11,
+        MediaGallery = 12,
+        File = 13,
+        Separator = 14,
+        Container = 17,
         _ => Unknown(u8),
     }
 }
@@ -40,6 +47,308 @@ pub struct ActionRow {
     pub components: Vec<ActionRowComponent>,
 }
 
+/// A top-level component of a message.
+///
+/// Messages with the [`MessageFlags::IS_COMPONENTS_V2`] flag may contain any of these in addition
+/// to action rows, which are the only valid top-level component otherwise.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#component-object-component-types).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Component {
+    ActionRow(ActionRow),
+    Section(Section),
+    TextDisplay(TextDisplay),
+    MediaGallery(MediaGallery),
+    File(FileComponent),
+    Separator(Separator),
+    Container(Container),
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::ActionRow => from_value(value).map(Component::ActionRow),
+            ComponentType::Section => from_value(value).map(Component::Section),
+            ComponentType::TextDisplay => from_value(value).map(Component::TextDisplay),
+            ComponentType::MediaGallery => from_value(value).map(Component::MediaGallery),
+            ComponentType::File => from_value(value).map(Component::File),
+            ComponentType::Separator => from_value(value).map(Component::Separator),
+            ComponentType::Container => from_value(value).map(Component::Container),
+            ComponentType::Unknown(i) => {
+                return Err(DeError::custom(format_args!("Unknown component type {i}")))
+            },
+            other => {
+                return Err(DeError::custom(format_args!(
+                    "Invalid top-level component type {other:?}"
+                )))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(c) => c.serialize(serializer),
+            Self::Section(c) => c.serialize(serializer),
+            Self::TextDisplay(c) => c.serialize(serializer),
+            Self::MediaGallery(c) => c.serialize(serializer),
+            Self::File(c) => c.serialize(serializer),
+            Self::Separator(c) => c.serialize(serializer),
+            Self::Container(c) => c.serialize(serializer),
+        }
+    }
+}
+
+/// A section component, pairing text with an accessory component.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#section).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Section {
+    /// Always [`ComponentType::Section`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The text displays of this section.
+    pub components: Vec<TextDisplay>,
+    /// The accessory shown next to the text.
+    pub accessory: SectionAccessory,
+}
+
+/// The accessory of a [`Section`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#section).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SectionAccessory {
+    Button(Button),
+    Thumbnail(Thumbnail),
+}
+
+impl<'de> Deserialize<'de> for SectionAccessory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::Button => from_value(value).map(SectionAccessory::Button),
+            ComponentType::Thumbnail => from_value(value).map(SectionAccessory::Thumbnail),
+            other => {
+                return Err(DeError::custom(format_args!(
+                    "Invalid section accessory type {other:?}"
+                )))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for SectionAccessory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Button(c) => c.serialize(serializer),
+            Self::Thumbnail(c) => c.serialize(serializer),
+        }
+    }
+}
+
+/// A text display component.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#text-display).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TextDisplay {
+    /// Always [`ComponentType::TextDisplay`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The markdown text content, including optional mentions.
+    pub content: String,
+}
+
+/// A thumbnail component, only valid as the accessory of a [`Section`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#thumbnail).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Thumbnail {
+    /// Always [`ComponentType::Thumbnail`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The displayed media.
+    pub media: UnfurledMediaItem,
+    /// Alt text for the media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the media is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A media gallery component, displaying between 1 and 10 media items in a gallery.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#media-gallery).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGallery {
+    /// Always [`ComponentType::MediaGallery`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The media items of this gallery.
+    pub items: Vec<MediaGalleryItem>,
+}
+
+/// A single media item in a [`MediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#media-gallery).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGalleryItem {
+    /// The displayed media.
+    pub media: UnfurledMediaItem,
+    /// Alt text for the media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the media is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A file component, displaying an uploaded file as an attachment.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#file).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FileComponent {
+    /// Always [`ComponentType::File`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The displayed file, referenced by an `attachment://<filename>` URL.
+    pub file: UnfurledMediaItem,
+    /// Whether the file is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+    /// The name of the file. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The size of the file in bytes. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+/// A separator component, adding vertical padding between other components.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#separator).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Separator {
+    /// Always [`ComponentType::Separator`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// Whether a visual divider is displayed. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub divider: bool,
+    /// The size of the separator padding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<SeparatorSpacingSize>,
+}
+
+enum_number! {
+    /// The padding size of a [`Separator`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#separator).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum SeparatorSpacingSize {
+        Small = 1,
+        Large = 2,
+        _ => Unknown(u8),
+    }
+}
+
+/// A container component, visually grouping other components with an optional accent colour.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#container).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Container {
+    /// Always [`ComponentType::Container`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// An optional identifier for the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// The components of this container.
+    pub components: Vec<Component>,
+    /// The accent colour displayed on the side of the container.
+    #[serde(rename = "accent_color", default, skip_serializing_if = "Option::is_none")]
+    pub accent_colour: Option<Colour>,
+    /// Whether the container contents are blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A media item displayed in a [`Thumbnail`], [`MediaGallery`] or [`FileComponent`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#unfurled-media-item-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UnfurledMediaItem {
+    /// The URL of the media, supporting arbitrary URLs and `attachment://<filename>` references.
+    pub url: String,
+    /// The proxied URL of the media. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    /// The height of the media. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// The width of the media. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// The media type of the content. Only available when receiving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
 /// A component which can be inside of an [`ActionRow`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#component-object-component-types).
@@ -67,12 +376,14 @@ impl<'de> Deserialize<'de> for ActionRowComponent {
             | ComponentType::RoleSelect
             | ComponentType::MentionableSelect
             | ComponentType::ChannelSelect => from_value(value).map(ActionRowComponent::SelectMenu),
-            ComponentType::ActionRow => {
-                return Err(DeError::custom("Invalid component type ActionRow"))
-            },
             ComponentType::Unknown(i) => {
                 return Err(DeError::custom(format_args!("Unknown component type {i}")))
             },
+            other => {
+                return Err(DeError::custom(format_args!(
+                    "Invalid component type in this context: {other:?}"
+                )))
+            },
         }
         .map_err(DeError::custom)
     }
@@ -205,6 +516,12 @@ pub struct SelectMenu {
     /// List of channel types to include in the [`ComponentType::ChannelSelect`].
     #[serde(default)]
     pub channel_types: Vec<ChannelType>,
+    /// The default values of this select menu.
+    ///
+    /// Only available for the auto-populated select menus, i.e. all except
+    /// [`ComponentType::StringSelect`].
+    #[serde(default)]
+    pub default_values: Vec<SelectMenuDefaultValue>,
     /// The placeholder shown when nothing is selected.
     pub placeholder: Option<String>,
     /// The minimum number of selections allowed.
@@ -216,6 +533,36 @@ pub struct SelectMenu {
     pub disabled: bool,
 }
 
+/// A default value of an auto-populated select menu.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SelectMenuDefaultValue {
+    /// The Id of the user, role, or channel.
+    pub id: GenericId,
+    /// The type of value the Id represents.
+    #[serde(rename = "type")]
+    pub kind: SelectMenuDefaultValueKind,
+}
+
+/// The type of a [`SelectMenuDefaultValue`].
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum SelectMenuDefaultValueKind {
+    User,
+    Role,
+    Channel,
+    /// Variant value is unknown.
+    #[serde(other)]
+    Unknown,
+}
+
 /// A select menu component options.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure).
@@ -324,4 +671,91 @@ mod tests {
             json!({"type": 2, "style": 5, "url": "https://google.com", "label": "a", "disabled": false}),
         );
     }
+
+    #[test]
+    fn test_select_menu_default_values_deserialize() {
+        let value = json!({
+            "type": 5,
+            "custom_id": "select",
+            "default_values": [
+                {"id": "123", "type": "user"},
+                {"id": "456", "type": "something else"},
+            ],
+        });
+
+        let menu: SelectMenu = crate::json::from_value(value).unwrap();
+        assert_eq!(menu.kind, ComponentType::UserSelect);
+
+        let [first, second] = menu.default_values.as_slice() else {
+            panic!("expected two default values: {menu:?}");
+        };
+        assert_eq!(first.id, GenericId::new(123));
+        assert_eq!(first.kind, SelectMenuDefaultValueKind::User);
+        assert_eq!(second.kind, SelectMenuDefaultValueKind::Unknown);
+    }
+
+    #[test]
+    fn test_row_component_deserialize() {
+        let value = json!({
+            "type": 1,
+            "components": [
+                {"type": 2, "style": 4, "custom_id": "hello", "disabled": false},
+                {"type": 3, "custom_id": "select", "options": [
+                    {"label": "a", "value": "b"},
+                ]},
+            ],
+        });
+
+        let row: ActionRow = crate::json::from_value(value).unwrap();
+        assert_eq!(row.kind, ComponentType::ActionRow);
+
+        let [ActionRowComponent::Button(button), ActionRowComponent::SelectMenu(menu)] =
+            row.components.as_slice()
+        else {
+            panic!("unexpected components: {:?}", row.components)
+        };
+
+        assert_eq!(button.data, ButtonKind::NonLink {
+            custom_id: "hello".into(),
+            style: ButtonStyle::Danger,
+        });
+        assert_eq!(menu.kind, ComponentType::StringSelect);
+        assert_eq!(menu.options.len(), 1);
+    }
+
+    #[test]
+    fn test_top_level_component_deserialize() {
+        let value = json!({
+            "type": 17,
+            "accent_color": 16711680,
+            "components": [
+                {"type": 10, "content": "hello"},
+                {"type": 14, "spacing": 2},
+                {
+                    "type": 9,
+                    "components": [{"type": 10, "content": "world"}],
+                    "accessory": {"type": 11, "media": {"url": "https://example.com/a.png"}},
+                },
+            ],
+        });
+
+        let Component::Container(container) = crate::json::from_value(value).unwrap() else {
+            panic!("expected a container");
+        };
+        assert_eq!(container.accent_colour, Some(Colour::new(0xFF0000)));
+
+        let [Component::TextDisplay(text), Component::Separator(separator), Component::Section(section)] =
+            container.components.as_slice()
+        else {
+            panic!("unexpected components: {:?}", container.components)
+        };
+
+        assert_eq!(text.content, "hello");
+        assert!(separator.divider);
+        assert_eq!(separator.spacing, Some(SeparatorSpacingSize::Large));
+        let SectionAccessory::Thumbnail(thumbnail) = &section.accessory else {
+            panic!("unexpected accessory: {:?}", section.accessory)
+        };
+        assert_eq!(thumbnail.media.url, "https://example.com/a.png");
+    }
 }
@@ -49,6 +49,9 @@ pub struct ComponentInteraction {
     #[serde(default)]
     pub user: User,
     /// A continuation token for responding to the interaction.
+    ///
+    /// This is valid for 15 minutes after the interaction was created. The expiry can be
+    /// computed from [`Self::id`]'s creation time.
     pub token: String,
     /// Always `1`.
     pub version: u8,
@@ -56,9 +59,13 @@ pub struct ComponentInteraction {
     pub message: Box<Message>,
     /// Permissions the app or bot has within the channel the interaction was sent from.
     pub app_permissions: Option<Permissions>,
-    /// The selected language of the invoking user.
+    /// The selected language of the invoking user, as an [IETF language tag].
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
     pub locale: String,
-    /// The guild's preferred locale.
+    /// The guild's preferred locale, as an [IETF language tag].
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
     pub guild_locale: Option<String>,
 }
 
@@ -253,6 +260,32 @@ pub enum ComponentInteractionDataKind {
     Unknown(u8),
 }
 
+impl ComponentInteractionDataKind {
+    /// Returns the [`ComponentType`] of the component this interaction originated from.
+    #[must_use]
+    pub fn component_type(&self) -> ComponentType {
+        match self {
+            Self::Button => ComponentType::Button,
+            Self::StringSelect {
+                ..
+            } => ComponentType::StringSelect,
+            Self::UserSelect {
+                ..
+            } => ComponentType::UserSelect,
+            Self::RoleSelect {
+                ..
+            } => ComponentType::RoleSelect,
+            Self::MentionableSelect {
+                ..
+            } => ComponentType::MentionableSelect,
+            Self::ChannelSelect {
+                ..
+            } => ComponentType::ChannelSelect,
+            Self::Unknown(x) => ComponentType::Unknown(*x),
+        }
+    }
+}
+
 // Manual impl needed to emulate integer enum tags
 impl<'de> Deserialize<'de> for ComponentInteractionDataKind {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
@@ -288,7 +321,7 @@ impl<'de> Deserialize<'de> for ComponentInteractionDataKind {
                 values: parse_values!(),
             },
             ComponentType::Unknown(x) => Self::Unknown(x),
-            x @ (ComponentType::ActionRow | ComponentType::InputText) => {
+            x => {
                 return Err(D::Error::custom(format_args!(
                     "invalid message component type in this context: {x:?}",
                 )));
@@ -106,7 +106,7 @@ pub struct TeamMember {
     pub membership_state: MembershipState,
     /// The list of permissions of the member on the team.
     ///
-    /// NOTE: Will always be ["*"] for now.
+    /// NOTE: Will always be `["*"]` for now.
     pub permissions: Vec<String>,
     /// The ID of the team they are a member of.
     pub team_id: GenericId,
@@ -158,7 +158,7 @@ bitflags! {
         /// found on the Bot page in your app's settings
         const GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
         /// Indicates if an app has registered global application commands
-        const APPLICATION_COMMAND_BADGE = 1 << 19;
+        const APPLICATION_COMMAND_BADGE = 1 << 23;
     }
 }
 
@@ -44,6 +44,9 @@ pub struct ModalInteraction {
     #[serde(default)]
     pub user: User,
     /// A continuation token for responding to the interaction.
+    ///
+    /// This is valid for 15 minutes after the interaction was created. The expiry can be
+    /// computed from [`Self::id`]'s creation time.
     pub token: String,
     /// Always `1`.
     pub version: u8,
@@ -55,9 +58,13 @@ pub struct ModalInteraction {
     pub message: Option<Box<Message>>,
     /// Permissions the app or bot has within the channel the interaction was sent from.
     pub app_permissions: Option<Permissions>,
-    /// The selected language of the invoking user.
+    /// The selected language of the invoking user, as an [IETF language tag].
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
     pub locale: String,
-    /// The guild's preferred locale.
+    /// The guild's preferred locale, as an [IETF language tag].
+    ///
+    /// [IETF language tag]: https://discord.com/developers/docs/reference#locales
     pub guild_locale: Option<String>,
 }
 
@@ -14,6 +14,9 @@ pub struct PingInteraction {
     /// Id of the application this interaction is for.
     pub application_id: ApplicationId,
     /// A continuation token for responding to the interaction.
+    ///
+    /// This is valid for 15 minutes after the interaction was created. The expiry can be
+    /// computed from [`Self::id`]'s creation time.
     pub token: String,
     /// Always `1`.
     pub version: u8,
@@ -1,4 +1,8 @@
 #[cfg(feature = "model")]
+use bytes::Bytes;
+#[cfg(feature = "model")]
+use futures::{Stream, TryStreamExt as _};
+#[cfg(feature = "model")]
 use reqwest::Client as ReqwestClient;
 
 #[cfg(feature = "model")]
@@ -154,4 +158,17 @@ impl Attachment {
         let bytes = reqwest.get(&self.url).send().await?.bytes().await?;
         Ok(bytes.to_vec())
     }
+
+    /// Downloads the attachment as a stream of byte chunks, without buffering the whole file in
+    /// memory. Prefer this over [`Self::download`] for large attachments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] when there is a problem retrieving the attachment. The stream
+    /// itself yields an [`Error::Http`] if the download fails partway through.
+    pub async fn download_stream(&self) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let reqwest = ReqwestClient::new();
+        let stream = reqwest.get(&self.url).send().await?.bytes_stream().map_err(Error::from);
+        Ok(stream)
+    }
 }
@@ -362,8 +362,9 @@ impl ChannelId {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission. [Manage Webhook]:
-    /// Permissions::MANAGE_WEBHOOKS
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Webhook]: Permissions::MANAGE_WEBHOOKS
     pub async fn follow(
         self,
         http: impl AsRef<Http>,
@@ -622,6 +623,36 @@ impl ChannelId {
             .await
     }
 
+    /// Gets the list of [`User`]s who have super-reacted to a [`Message`] with a certain
+    /// [`Emoji`].
+    ///
+    /// This is the same as [`Self::reaction_users`], except that only super reactions are listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to read messages in the
+    /// channel.
+    pub async fn burst_reaction_users(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        let limit = limit.map_or(50, |x| if x > 100 { 100 } else { x });
+
+        http.as_ref()
+            .get_burst_reaction_users(
+                self,
+                message_id.into(),
+                &reaction_type.into(),
+                limit,
+                after.into().map(UserId::get),
+            )
+            .await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// **Note**: Message content must be under 2000 unicode code points.
@@ -1055,7 +1086,7 @@ impl From<Channel> for ChannelId {
 }
 
 #[cfg(feature = "model")]
-impl<'a> From<&'a Channel> for ChannelId {
+impl From<&Channel> for ChannelId {
     /// Gets the Id of a [`Channel`].
     fn from(channel: &Channel) -> ChannelId {
         channel.id()
@@ -1069,7 +1100,7 @@ impl From<PrivateChannel> for ChannelId {
     }
 }
 
-impl<'a> From<&'a PrivateChannel> for ChannelId {
+impl From<&PrivateChannel> for ChannelId {
     /// Gets the Id of a private channel.
     fn from(private_channel: &PrivateChannel) -> ChannelId {
         private_channel.id
@@ -1083,7 +1114,7 @@ impl From<GuildChannel> for ChannelId {
     }
 }
 
-impl<'a> From<&'a GuildChannel> for ChannelId {
+impl From<&GuildChannel> for ChannelId {
     /// Gets the Id of a guild channel.
     fn from(public_channel: &GuildChannel) -> ChannelId {
         public_channel.id
@@ -1097,7 +1128,7 @@ impl From<WebhookChannel> for ChannelId {
     }
 }
 
-impl<'a> From<&'a WebhookChannel> for ChannelId {
+impl From<&WebhookChannel> for ChannelId {
     /// Gets the Id of a webhook channel.
     fn from(webhook_channel: &WebhookChannel) -> ChannelId {
         webhook_channel.id
@@ -200,10 +200,55 @@ impl GuildChannel {
     /// Whether or not this channel is text-based, meaning that it is possible to send messages.
     #[must_use]
     pub fn is_text_based(&self) -> bool {
-        matches!(
-            self.kind,
-            ChannelType::Text | ChannelType::News | ChannelType::Voice | ChannelType::Stage
-        )
+        self.kind.is_text_based()
+    }
+
+    /// Whether or not this channel is a news (announcement) channel, meaning that it can be
+    /// followed via [`ChannelId::follow`].
+    #[must_use]
+    pub fn is_news(&self) -> bool {
+        self.kind == ChannelType::News
+    }
+
+    /// Whether or not this channel is a thread.
+    #[must_use]
+    pub fn is_thread(&self) -> bool {
+        self.kind.is_thread()
+    }
+
+    /// Returns the voice-specific data of this channel, if it is a voice or stage channel.
+    ///
+    /// This is the typed way to read fields such as [`Self::bitrate`] and [`Self::user_limit`],
+    /// which are only ever present for voice-based kinds.
+    #[must_use]
+    pub fn voice_data(&self) -> Option<VoiceChannelData<'_>> {
+        if !self.kind.is_voice_based() {
+            return None;
+        }
+
+        Some(VoiceChannelData {
+            bitrate: self.bitrate?,
+            user_limit: self.user_limit?,
+            rtc_region: self.rtc_region.as_deref(),
+            video_quality_mode: self.video_quality_mode.unwrap_or(VideoQualityMode::Auto),
+        })
+    }
+
+    /// Returns the thread-specific data of this channel, if it is a thread.
+    ///
+    /// This is the typed way to read fields such as [`Self::thread_metadata`] and
+    /// [`Self::parent_id`], which are only ever present for thread kinds.
+    #[must_use]
+    pub fn thread_data(&self) -> Option<ThreadData<'_>> {
+        if !self.kind.is_thread() {
+            return None;
+        }
+
+        Some(ThreadData {
+            parent_id: self.parent_id?,
+            metadata: self.thread_metadata?,
+            member: self.member.as_ref(),
+        })
     }
 
     /// Broadcasts to the channel that the current user is typing.
@@ -1019,13 +1064,9 @@ impl GuildChannel {
                 .collect()),
             ChannelType::News | ChannelType::Text => Ok(guild
                 .members
-                .iter()
-                .filter(|e| {
-                    self.permissions_for_user(cache, e.0)
-                        .map(|p| p.contains(Permissions::VIEW_CHANNEL))
-                        .unwrap_or(false)
-                })
-                .map(|e| e.1.clone())
+                .values()
+                .filter(|member| guild.user_permissions_in(self, member).view_channel())
+                .cloned()
                 .collect::<Vec<Member>>()),
             _ => Err(Error::from(ModelError::InvalidChannelType)),
         }
@@ -1182,6 +1223,34 @@ impl fmt::Display for GuildChannel {
     }
 }
 
+/// The voice-specific data of a voice or stage [`GuildChannel`], as returned by
+/// [`GuildChannel::voice_data`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct VoiceChannelData<'a> {
+    /// The bitrate of the channel.
+    pub bitrate: u32,
+    /// The maximum number of members allowed in the channel, with 0 meaning no limit.
+    pub user_limit: u32,
+    /// The voice region of the channel. [`None`] means it is chosen automatically.
+    pub rtc_region: Option<&'a str>,
+    /// The camera video quality mode of the channel.
+    pub video_quality_mode: VideoQualityMode,
+}
+
+/// The thread-specific data of a thread [`GuildChannel`], as returned by
+/// [`GuildChannel::thread_data`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ThreadData<'a> {
+    /// The Id of the text, news, or forum channel the thread was created in.
+    pub parent_id: ChannelId,
+    /// The archival and locking state of the thread.
+    pub metadata: ThreadMetadata,
+    /// The current user's thread membership, if the user has joined the thread.
+    pub member: Option<&'a PartialThreadMember>,
+}
+
 /// A partial guild channel.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-object),
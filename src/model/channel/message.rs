@@ -2,8 +2,6 @@
 
 #[cfg(feature = "model")]
 use std::fmt::Display;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::fmt::Write;
 
 #[cfg(all(feature = "model", feature = "utils"))]
 use crate::builder::{Builder, CreateAllowedMentions, CreateMessage, EditMessage};
@@ -14,6 +12,7 @@ use crate::collector::{
     ComponentInteractionCollector,
     ModalInteractionCollector,
     ReactionCollector,
+    ReactionRemoveCollector,
 };
 #[cfg(feature = "model")]
 use crate::constants;
@@ -21,8 +20,9 @@ use crate::constants;
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http};
-use crate::model::application::{ActionRow, MessageInteraction};
+use crate::model::application::{Component, MessageInteraction};
 use crate::model::prelude::*;
+use crate::model::utils::hex_colours;
 #[cfg(all(feature = "model", feature = "cache"))]
 use crate::utils;
 
@@ -100,8 +100,15 @@ pub struct Message {
     /// If the message is an Interaction or application-owned webhook, this is the id of the
     /// application.
     pub application_id: Option<ApplicationId>,
-    /// Reference data sent with crossposted messages.
+    /// Reference data sent with crossposted messages, replies and forwards.
     pub message_reference: Option<MessageReference>,
+    /// Partial copies of the messages pointed at by this message's [`Self::message_reference`],
+    /// sent with forwards.
+    ///
+    /// Discord currently only allows forwarding a single message at a time, so this contains at
+    /// most one snapshot.
+    #[serde(default)]
+    pub message_snapshots: Vec<MessageSnapshot>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
@@ -114,7 +121,7 @@ pub struct Message {
     pub thread: Option<GuildChannel>,
     /// The components of this message
     #[serde(default)]
-    pub components: Vec<ActionRow>,
+    pub components: Vec<Component>,
     /// Array of message sticker item objects.
     #[serde(default)]
     pub sticker_items: Vec<StickerItem>,
@@ -187,6 +194,32 @@ impl Message {
         self.channel_id.crosspost(cache_http.http(), self.id).await
     }
 
+    /// Forwards this message to another channel.
+    ///
+    /// **Note**: Requires the [Send Messages] permission in the target channel, as well as the
+    /// [Read Message History] permission in this message's channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to forward the message, or if
+    /// the message cannot be forwarded.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn forward_to(
+        &self,
+        cache_http: impl CacheHttp,
+        channel_id: ChannelId,
+    ) -> Result<Message> {
+        let reference = MessageReference {
+            kind: MessageReferenceKind::Forward,
+            ..MessageReference::from(self)
+        };
+
+        let builder = CreateMessage::new().reference_message(reference);
+        channel_id.send_message(cache_http, builder).await
+    }
+
     /// First attempts to find a [`Channel`] by its Id in the cache, upon failure requests it via
     /// the REST API.
     ///
@@ -364,46 +397,64 @@ impl Message {
         Ok(())
     }
 
-    /// Returns message content, but with user and role mentions replaced with
-    /// names and everyone/here mentions cancelled.
-    #[cfg(feature = "cache")]
-    pub fn content_safe(&self, cache: impl AsRef<Cache>) -> String {
-        let mut result = self.content.clone();
-
-        // First replace all user mentions.
-        for u in &self.mentions {
-            let mut at_distinct = String::with_capacity(38);
-            at_distinct.push('@');
-            at_distinct.push_str(&u.name);
-            if let Some(discriminator) = u.discriminator {
-                at_distinct.push('#');
-                write!(at_distinct, "{:04}", discriminator.get()).unwrap();
-            }
-
-            let mut m = u.mention().to_string();
-            // Check whether we're replacing a nickname mention or a normal mention.
-            // `UserId::mention` returns a normal mention. If it isn't present in the message, it's
-            // a nickname mention.
-            if !result.contains(&m) {
-                m.insert(2, '!');
+    /// Suppresses or unsuppresses embeds on this message, hiding or restoring its link previews.
+    ///
+    /// Unlike [`Self::edit`], this may also be used on messages authored by other users, as only
+    /// the message's flags are modified.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission when used on a message authored by
+    /// another user.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current
+    /// user does not have the required permissions. Otherwise returns [`Error::Http`] if the
+    /// current user lacks permission.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn suppress_embeds(
+        &mut self,
+        cache_http: impl CacheHttp,
+        suppress: bool,
+    ) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.author.id != cache.current_user().id && self.guild_id.is_some() {
+                    utils::user_has_perms_cache(
+                        cache,
+                        self.channel_id,
+                        Permissions::MANAGE_MESSAGES,
+                    )?;
+                }
             }
-
-            result = result.replace(&m, &at_distinct);
         }
 
-        // Then replace all role mentions.
-        for id in &self.mention_roles {
-            let mention = id.mention().to_string();
+        let builder = EditMessage::new().suppress_embeds(suppress);
+        *self = builder.execute(cache_http, (self.channel_id, self.id)).await?;
+        Ok(())
+    }
 
-            if let Some(role) = id.to_role_cached(&cache) {
-                result = result.replace(&mention, &format!("@{}", role.name));
-            } else {
-                result = result.replace(&mention, "@deleted-role");
-            }
+    /// Returns message content, but with user, role and channel mentions replaced with names and
+    /// everyone/here mentions cancelled.
+    ///
+    /// Mentioned users are resolved from [`Self::mentions`], falling back to the cache; roles and
+    /// channels are resolved from the cache. If the message was sent in a guild, users are
+    /// displayed with their nickname where one is set.
+    ///
+    /// To control which mention types are cleaned, call [`utils::content_safe`] directly with
+    /// custom [`ContentSafeOptions`].
+    ///
+    /// [`ContentSafeOptions`]: crate::utils::ContentSafeOptions
+    /// [`utils::content_safe`]: crate::utils::content_safe
+    #[cfg(feature = "cache")]
+    pub fn content_safe(&self, cache: impl AsRef<Cache>) -> String {
+        let mut options = utils::ContentSafeOptions::default();
+        if let Some(guild_id) = self.guild_id {
+            options = options.display_as_member_from(guild_id);
         }
 
-        // And finally replace everyone and here mentions.
-        result.replace("@everyone", "@\u{200B}everyone").replace("@here", "@\u{200B}here")
+        utils::content_safe(cache, &self.content, &options, &self.mentions)
     }
 
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a certain [`Emoji`].
@@ -437,6 +488,25 @@ impl Message {
         self.channel_id.reaction_users(http, self.id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have super-reacted to a [`Message`] with a certain
+    /// [`Emoji`].
+    ///
+    /// This is the same as [`Self::reaction_users`], except that only super reactions are listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn burst_reaction_users(
+        &self,
+        http: impl AsRef<Http>,
+        reaction_type: impl Into<ReactionType>,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.channel_id.burst_reaction_users(http, self.id, reaction_type, limit, after).await
+    }
+
     /// Returns the associated [`Guild`] for the message if one is in the cache.
     ///
     /// Returns [`None`] if the guild's Id could not be found via [`Self::guild_id`] or if the
@@ -562,6 +632,8 @@ impl Message {
             user_id,
             guild_id: self.guild_id,
             member: self.member.as_deref().map(|member| member.clone().into()),
+            burst: false,
+            burst_colours: Vec::new(),
         })
     }
 
@@ -775,6 +847,25 @@ impl Message {
         self.await_reaction(shard_messenger)
     }
 
+    /// Returns a builder which can be awaited to obtain a removed reaction or stream of removed
+    /// reactions on this message.
+    #[cfg(feature = "collector")]
+    pub fn await_reaction_remove(
+        &self,
+        shard_messenger: impl AsRef<ShardMessenger>,
+    ) -> ReactionRemoveCollector {
+        ReactionRemoveCollector::new(shard_messenger).message_id(self.id)
+    }
+
+    /// Same as [`Self::await_reaction_remove`].
+    #[cfg(feature = "collector")]
+    pub fn await_reaction_removes(
+        &self,
+        shard_messenger: impl AsRef<ShardMessenger>,
+    ) -> ReactionRemoveCollector {
+        self.await_reaction_remove(shard_messenger)
+    }
+
     /// Returns a builder which can be awaited to obtain a single component interactions or a
     /// stream of component interactions on this message.
     #[cfg(feature = "collector")]
@@ -832,7 +923,7 @@ impl From<Message> for MessageId {
     }
 }
 
-impl<'a> From<&'a Message> for MessageId {
+impl From<&Message> for MessageId {
     /// Gets the Id of a [`Message`].
     fn from(message: &Message) -> MessageId {
         message.id
@@ -851,13 +942,36 @@ impl<'a> From<&'a Message> for MessageId {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReaction {
-    /// The amount of the type of reaction that have been sent for the associated message.
+    /// The amount of the type of reaction that have been sent for the associated message,
+    /// including super reactions.
     pub count: u64,
+    /// A breakdown of the amount of normal and super reactions.
+    #[serde(default)]
+    pub count_details: ReactionCountDetails,
     /// Indicator of whether the current user has sent the type of reaction.
     pub me: bool,
+    /// Indicator of whether the current user has sent the type of reaction as a super reaction.
+    #[serde(default)]
+    pub me_burst: bool,
     /// The type of reaction.
     #[serde(rename = "emoji")]
     pub reaction_type: ReactionType,
+    /// The colours used for the super reaction.
+    #[serde(rename = "burst_colors", with = "hex_colours", default)]
+    pub burst_colours: Vec<Colour>,
+}
+
+/// A breakdown of the amount of normal and super reactions for the associated emoji.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#reaction-count-details-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ReactionCountDetails {
+    /// The amount of super reactions.
+    pub burst: u64,
+    /// The amount of normal reactions.
+    pub normal: u64,
 }
 
 enum_number! {
@@ -985,6 +1099,9 @@ pub struct MessageActivity {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
+    /// The type of reference, i.e. whether it points at a reply or a forward.
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceKind,
     /// ID of the originating message.
     pub message_id: Option<MessageId>,
     /// ID of the originating message's channel.
@@ -999,6 +1116,7 @@ pub struct MessageReference {
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
+            kind: MessageReferenceKind::default(),
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
@@ -1010,6 +1128,7 @@ impl From<&Message> for MessageReference {
 impl From<(ChannelId, MessageId)> for MessageReference {
     fn from(pair: (ChannelId, MessageId)) -> Self {
         Self {
+            kind: MessageReferenceKind::default(),
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
@@ -1018,6 +1137,75 @@ impl From<(ChannelId, MessageId)> for MessageReference {
     }
 }
 
+enum_number! {
+    /// Determines what a [`MessageReference`] points at.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/message#message-reference-types).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum MessageReferenceKind {
+        /// A standard reference, used by replies and crossposts.
+        #[default]
+        Default = 0,
+        /// A reference used to point at the snapshot of a forwarded message.
+        Forward = 1,
+        _ => Unknown(u8),
+    }
+}
+
+/// A snapshot of a forwarded message, attached to the forwarding message.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/message#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageSnapshot {
+    /// The copied contents of the forwarded message.
+    pub message: ForwardedMessage,
+}
+
+/// A partial copy of a forwarded [`Message`].
+///
+/// Only a minimal subset of the original message's fields is copied over. Most notably, the
+/// author is absent; the original message can be looked up through the forwarding message's
+/// [`MessageReference`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/message#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForwardedMessage {
+    /// The type of the original message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// The contents of the original message.
+    pub content: String,
+    /// The embeds of the original message.
+    pub embeds: Vec<Embed>,
+    /// The files attached to the original message.
+    pub attachments: Vec<Attachment>,
+    /// The initial creation time of the original message.
+    pub timestamp: Timestamp,
+    /// The time of the original message's last edit, if it was edited.
+    pub edited_timestamp: Option<Timestamp>,
+    /// Bit flags describing extra features of the original message.
+    pub flags: Option<MessageFlags>,
+    /// The users mentioned in the original message.
+    #[serde(default)]
+    pub mentions: Vec<User>,
+    /// The roles mentioned in the original message.
+    #[serde(default)]
+    pub mention_roles: Vec<RoleId>,
+    /// The sticker items of the original message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
+    /// The components of the original message.
+    #[serde(default)]
+    pub components: Vec<Component>,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-mention-object).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1071,9 +1259,18 @@ bitflags! {
         /// As of 2023-04-14, clients upload a 1 channel, 48000 Hz, 32kbps Opus stream in an OGG container.
         /// The encoding is a Discord implementation detail and may change without warning or documentation.
         ///
-        /// As of 2023-04-20, bots are currently not able to send voice messages
-        /// ([source](https://github.com/discord/discord-api-docs/pull/6082)).
+        /// Bots can send voice messages by setting this flag on an otherwise empty message whose
+        /// single audio attachment carries the voice message metadata, see
+        /// [`CreateAttachment::voice_message_metadata`].
+        ///
+        /// [`CreateAttachment::voice_message_metadata`]: crate::builder::CreateAttachment::voice_message_metadata
         const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message uses the components V2 layout, allowing components such as [`Section`]
+        /// and [`Container`] at the top level.
+        ///
+        /// Once set, the flag cannot be removed from a message, and the message cannot have
+        /// `content`, `embeds`, `stickers` or `poll` set.
+        const IS_COMPONENTS_V2 = 1 << 15;
     }
 }
 
@@ -1081,6 +1278,21 @@ bitflags! {
 impl MessageId {
     /// Returns a link referencing this message. When clicked, users will jump to the message. The
     /// link will be valid for messages in either private channels or guilds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::id::{ChannelId, GuildId, MessageId};
+    ///
+    /// let message_id = MessageId::new(8);
+    /// let channel_id = ChannelId::new(7);
+    ///
+    /// assert_eq!(
+    ///     message_id.link(channel_id, Some(GuildId::new(9))),
+    ///     "https://discord.com/channels/9/7/8"
+    /// );
+    /// assert_eq!(message_id.link(channel_id, None), "https://discord.com/channels/@me/7/8");
+    /// ```
     #[must_use]
     pub fn link(&self, channel_id: ChannelId, guild_id: Option<GuildId>) -> String {
         if let Some(guild_id) = guild_id {
@@ -14,6 +14,8 @@ use std::fmt;
 use serde::de::{Error as DeError, Unexpected};
 
 pub use self::attachment::*;
+// Without the model feature, channel_id only contains impl blocks and exports nothing.
+#[cfg(feature = "model")]
 pub use self::channel_id::*;
 pub use self::embed::*;
 pub use self::guild_channel::*;
@@ -204,7 +206,7 @@ impl fmt::Display for Channel {
     /// This will return a different format for each type of channel:
     /// - [`PrivateChannel`]s: the recipient's name;
     /// - [`GuildChannel`]s: a string mentioning the channel that users who can see the channel can
-    /// click on.
+    ///   click on.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Guild(ch) => fmt::Display::fmt(&ch.id.mention(), f),
@@ -275,6 +277,45 @@ impl ChannelType {
             Self::Unknown(_) => "unknown",
         }
     }
+
+    /// Whether the channel is a thread within a text, news, or forum channel.
+    #[inline]
+    #[must_use]
+    pub const fn is_thread(&self) -> bool {
+        matches!(*self, Self::NewsThread | Self::PublicThread | Self::PrivateThread)
+    }
+
+    /// Whether the channel can carry voice, i.e. is a voice or stage channel.
+    ///
+    /// Fields such as [`GuildChannel::bitrate`], [`GuildChannel::user_limit`], and
+    /// [`GuildChannel::rtc_region`] are only ever present for these kinds.
+    #[inline]
+    #[must_use]
+    pub const fn is_voice_based(&self) -> bool {
+        matches!(*self, Self::Voice | Self::Stage)
+    }
+
+    /// Whether messages can be sent directly to the channel, i.e. it is not a category, directory,
+    /// or forum. Fields such as [`GuildChannel::topic`] and
+    /// [`GuildChannel::rate_limit_per_user`] only apply to these kinds.
+    ///
+    /// Note that this does not check any permissions.
+    #[inline]
+    #[must_use]
+    pub const fn is_text_based(&self) -> bool {
+        matches!(
+            *self,
+            Self::Text
+                | Self::Private
+                | Self::Voice
+                | Self::GroupDm
+                | Self::News
+                | Self::NewsThread
+                | Self::PublicThread
+                | Self::PrivateThread
+                | Self::Stage
+        )
+    }
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#overwrite-object).
@@ -484,6 +525,28 @@ pub struct ThreadsData {
 
 #[cfg(test)]
 mod test {
+    use crate::json::{assert_json, json};
+    use crate::model::prelude::*;
+
+    #[test]
+    fn permission_overwrite_serde() {
+        let member = PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL,
+            deny: Permissions::SEND_TTS_MESSAGES,
+            kind: PermissionOverwriteType::Member(UserId::new(1234)),
+        };
+
+        assert_json(&member, json!({"allow": "1024", "deny": "4096", "id": "1234", "type": 1}));
+
+        let role = PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::MENTION_EVERYONE,
+            kind: PermissionOverwriteType::Role(RoleId::new(5678)),
+        };
+
+        assert_json(&role, json!({"allow": "0", "deny": "131072", "id": "5678", "type": 0}));
+    }
+
     #[cfg(all(feature = "model", feature = "utils"))]
     mod model_utils {
         use crate::model::prelude::*;
@@ -16,6 +16,7 @@ use tracing::warn;
 use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+use crate::model::utils::hex_colours;
 
 /// An emoji reaction to a message.
 ///
@@ -40,6 +41,12 @@ pub struct Reaction {
     pub member: Option<Member>,
     /// The reactive emoji used.
     pub emoji: ReactionType,
+    /// Whether the reaction is a super reaction.
+    #[serde(default)]
+    pub burst: bool,
+    /// The colours used for the super reaction.
+    #[serde(rename = "burst_colors", with = "hex_colours", default)]
+    pub burst_colours: Vec<Colour>,
 }
 
 // Manual impl needed to insert guild_id into PartialMember
@@ -269,6 +276,10 @@ pub enum ReactionType {
         name: Option<String>,
     },
     /// A reaction with a twemoji.
+    ///
+    /// This must hold the emoji's actual unicode characters, e.g. `"🗳️"`. Shortcodes such as
+    /// `:ballot_box:` are resolved client-side only; sending one to the API results in an opaque
+    /// `Unknown Emoji` error.
     Unicode(String),
 }
 
@@ -456,7 +467,7 @@ impl TryFrom<String> for ReactionType {
     }
 }
 
-impl<'a> TryFrom<&'a str> for ReactionType {
+impl TryFrom<&str> for ReactionType {
     /// Creates a [`ReactionType`] from a string slice.
     ///
     /// # Examples
@@ -497,7 +508,6 @@ impl<'a> TryFrom<&'a str> for ReactionType {
     ///
     /// assert_eq!(reaction, reaction2);
     /// ```
-
     type Error = ReactionConversionError;
 
     fn try_from(emoji_str: &str) -> std::result::Result<Self, Self::Error> {
@@ -569,3 +579,33 @@ impl fmt::Display for ReactionType {
         }
     }
 }
+
+#[allow(clippy::non_ascii_literal)]
+#[cfg(test)]
+mod tests {
+    use super::ReactionType;
+    use crate::model::id::EmojiId;
+
+    #[test]
+    fn parse_reaction_type() {
+        assert_eq!("🍎".parse::<ReactionType>().unwrap(), ReactionType::Unicode("🍎".to_string()));
+
+        let custom = ReactionType::Custom {
+            animated: false,
+            id: EmojiId::new(600404340292059257),
+            name: Some("customemoji".to_string()),
+        };
+        assert_eq!("<:customemoji:600404340292059257>".parse::<ReactionType>().unwrap(), custom);
+
+        let animated = ReactionType::Custom {
+            animated: true,
+            id: EmojiId::new(600404340292059257),
+            name: Some("customemoji".to_string()),
+        };
+        assert_eq!("<a:customemoji:600404340292059257>".parse::<ReactionType>().unwrap(), animated);
+
+        assert!("".parse::<ReactionType>().is_err());
+        assert!("<:customemoji:600404340292059257".parse::<ReactionType>().is_err());
+        assert!("<:customemoji:notanid>".parse::<ReactionType>().is_err());
+    }
+}
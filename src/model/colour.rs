@@ -126,6 +126,38 @@ impl Colour {
         Colour((red as u32) << 16 | (green as u32) << 8 | blue as u32)
     }
 
+    /// Generates a new Colour from a hex string, with or without a leading `#`.
+    ///
+    /// Both the 6-digit `RRGGBB` form and the 3-digit `RGB` shorthand are accepted.
+    ///
+    /// If the string is not a valid hex colour, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Colour;
+    ///
+    /// assert_eq!(Colour::from_hex_str("#7289da"), Some(Colour::new(0x7289DA)));
+    /// assert_eq!(Colour::from_hex_str("7289DA"), Some(Colour::new(0x7289DA)));
+    /// assert_eq!(Colour::from_hex_str("#fff"), Some(Colour::new(0xFFFFFF)));
+    ///
+    /// assert_eq!(Colour::from_hex_str("#12345"), None);
+    /// assert_eq!(Colour::from_hex_str("splursh"), None);
+    /// ```
+    #[must_use]
+    pub fn from_hex_str(s: &str) -> Option<Colour> {
+        let s = s.strip_prefix('#').unwrap_or(s);
+        match s.len() {
+            6 => u32::from_str_radix(s, 16).ok().map(Colour),
+            3 => {
+                let value = u32::from_str_radix(s, 16).ok()?;
+                let (r, g, b) = ((value >> 8) & 0xF, (value >> 4) & 0xF, value & 0xF);
+                Some(Colour(((r * 0x11) << 16) | ((g * 0x11) << 8) | (b * 0x11)))
+            },
+            _ => None,
+        }
+    }
+
     /// Returns the red RGB component of this Colour.
     ///
     /// # Examples
@@ -402,7 +434,6 @@ pub mod colours {
 
 #[cfg(test)]
 mod test {
-    use std::u32;
 
     use super::Colour;
 
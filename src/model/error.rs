@@ -26,12 +26,12 @@ use super::Permissions;
 /// #[cfg(feature = "client")]
 /// impl EventHandler for Handler {
 ///     async fn guild_ban_removal(&self, context: Context, guild_id: GuildId, user: User) {
-///         match guild_id.ban(&context, user, 8).await {
+///         match guild_id.ban(&context, user, 604801).await {
 ///             Ok(()) => {
 ///                 // Ban successful.
 ///             },
-///             Err(Error::Model(ModelError::DeleteMessageDaysAmount(amount))) => {
-///                 println!("Failed deleting {} days' worth of messages", amount);
+///             Err(Error::Model(ModelError::DeleteMessageSecondsAmount(amount))) => {
+///                 println!("Failed deleting {} seconds' worth of messages", amount);
 ///             },
 ///             Err(why) => {
 ///                 println!("Unexpected error: {:?}", why);
@@ -50,8 +50,8 @@ use super::Permissions;
 pub enum Error {
     /// When attempting to delete below or above the minimum or maximum allowed number of messages.
     BulkDeleteAmount,
-    /// When attempting to delete a number of days' worth of messages that is not allowed.
-    DeleteMessageDaysAmount(u8),
+    /// When attempting to delete a number of seconds' worth of messages that is not allowed.
+    DeleteMessageSecondsAmount(u32),
     /// When attempting to send a message with over 10 embeds.
     EmbedAmount,
     /// Indicates that the textual content of an embed exceeds the maximum length.
@@ -172,7 +172,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
-            Self::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
+            Self::DeleteMessageSecondsAmount(_) => f.write_str("Invalid delete message seconds."),
             Self::EmbedAmount => f.write_str("Too many embeds in a message."),
             Self::EmbedTooLarge(_) => f.write_str("Embed too large."),
             Self::GuildNotFound => f.write_str("Guild not found in the cache."),
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use serde::de::Error as DeError;
 use serde::Serialize;
 
-use super::application::ActionRow;
+use super::application::Component;
 use super::prelude::*;
 use super::utils::{deserialize_val, emojis, remove_from_map, remove_from_map_opt, stickers};
 use crate::constants::Opcode;
@@ -376,14 +376,49 @@ pub struct GuildStickersUpdateEvent {
     pub guild_id: GuildId,
 }
 
-/// Requires [`GatewayIntents::GUILD_INVITES`] and [`Permissions::MANAGE_CHANNELS´] permission.
+/// [Discord docs](https://discord.com/developers/docs/events/gateway-events#guild-soundboard-sound-create).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundCreateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/events/gateway-events#guild-soundboard-sound-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundUpdateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/events/gateway-events#guild-soundboard-sound-delete).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundDeleteEvent {
+    pub sound_id: SoundboardSoundId,
+    pub guild_id: GuildId,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/events/gateway-events#guild-soundboard-sounds-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundsUpdateEvent {
+    pub soundboard_sounds: Vec<SoundboardSound>,
+    pub guild_id: GuildId,
+}
+
+/// Requires [`GatewayIntents::GUILD_INVITES`] and [`Permissions::MANAGE_CHANNELS`] permission.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#invite-create).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct InviteCreateEvent {
-    /// Whether or not the invite is temporary (invited users will be kicked on disconnect unless
     /// Channel the invite is for.
     pub channel_id: ChannelId,
     /// Unique invite [code](Invite::code).
@@ -404,13 +439,14 @@ pub struct InviteCreateEvent {
     pub target_user: Option<User>,
     /// Embedded application to open for this voice channel embedded application invite.
     pub target_application: Option<Value>,
+    /// Whether or not the invite is temporary (invited users will be kicked on disconnect unless
     /// they're assigned a role).
     pub temporary: bool,
     /// How many times the invite has been used (always will be 0).
     pub uses: u64,
 }
 
-/// Requires [`GatewayIntents::GUILD_INVITES`] and [`Permissions::MANAGE_CHANNELS´] permission.
+/// Requires [`GatewayIntents::GUILD_INVITES`] and [`Permissions::MANAGE_CHANNELS`] permission.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#invite-delete).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
@@ -526,7 +562,7 @@ pub struct MessageUpdateEvent {
     pub interaction: Option<Option<Box<MessageInteraction>>>,
     #[serde(default, deserialize_with = "deserialize_some")]
     pub thread: Option<Option<GuildChannel>>,
-    pub components: Option<Vec<ActionRow>>,
+    pub components: Option<Vec<Component>>,
     pub sticker_items: Option<Vec<StickerItem>>,
     pub position: Option<Option<u64>>,
     pub role_subscription_data: Option<Option<RoleSubscriptionData>>,
@@ -712,6 +748,7 @@ pub struct ResumedEvent {}
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#typing-start).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct TypingStartEvent {
     /// ID of the channel.
@@ -726,6 +763,23 @@ pub struct TypingStartEvent {
     pub member: Option<Member>,
 }
 
+// Manual impl needed to insert guild_id field in Member
+impl<'de> Deserialize<'de> for TypingStartEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut event = Self::deserialize(deserializer)?; // calls #[serde(remote)]-generated inherent method
+        if let (Some(guild_id), Some(member)) = (event.guild_id, event.member.as_mut()) {
+            member.guild_id = guild_id;
+        }
+        Ok(event)
+    }
+}
+
+impl Serialize for TypingStartEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        Self::serialize(self, serializer) // calls #[serde(remote)]-generated inherent method
+    }
+}
+
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
@@ -1155,6 +1209,34 @@ pub enum Event {
     GuildRoleCreate(GuildRoleCreateEvent),
     GuildRoleDelete(GuildRoleDeleteEvent),
     GuildRoleUpdate(GuildRoleUpdateEvent),
+    /// A [`SoundboardSound`] was created.
+    ///
+    /// Fires the [`EventHandler::guild_soundboard_sound_create`] event.
+    ///
+    /// [`EventHandler::guild_soundboard_sound_create`]:
+    /// crate::client::EventHandler::guild_soundboard_sound_create
+    GuildSoundboardSoundCreate(GuildSoundboardSoundCreateEvent),
+    /// A [`SoundboardSound`] was updated.
+    ///
+    /// Fires the [`EventHandler::guild_soundboard_sound_update`] event.
+    ///
+    /// [`EventHandler::guild_soundboard_sound_update`]:
+    /// crate::client::EventHandler::guild_soundboard_sound_update
+    GuildSoundboardSoundUpdate(GuildSoundboardSoundUpdateEvent),
+    /// A [`SoundboardSound`] was deleted.
+    ///
+    /// Fires the [`EventHandler::guild_soundboard_sound_delete`] event.
+    ///
+    /// [`EventHandler::guild_soundboard_sound_delete`]:
+    /// crate::client::EventHandler::guild_soundboard_sound_delete
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDeleteEvent),
+    /// Multiple [`SoundboardSound`]s were updated.
+    ///
+    /// Fires the [`EventHandler::guild_soundboard_sounds_update`] event.
+    ///
+    /// [`EventHandler::guild_soundboard_sounds_update`]:
+    /// crate::client::EventHandler::guild_soundboard_sounds_update
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdateEvent),
     /// A [`Sticker`] was created, updated, or deleted
     GuildStickersUpdate(GuildStickersUpdateEvent),
     GuildUpdate(GuildUpdateEvent),
@@ -262,6 +262,7 @@ impl PresenceUser {
     pub fn into_user(self) -> Option<User> {
         Some(User {
             avatar: self.avatar,
+            avatar_decoration_data: None,
             bot: self.bot?,
             discriminator: self.discriminator,
             global_name: None,
@@ -374,6 +375,7 @@ pub struct ShardInfo {
 }
 
 impl ShardInfo {
+    #[cfg(any(feature = "cache", feature = "gateway"))]
     #[must_use]
     pub(crate) fn new(id: ShardId, total: u32) -> Self {
         Self {
@@ -772,3 +774,81 @@ impl Default for GatewayIntents {
         Self::non_privileged()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn presence_custom_status() {
+        let value = json!({
+            "user": {
+                "id": "123"
+            },
+            "status": "online",
+            "activities": [{
+                "type": 4,
+                "name": "Custom Status",
+                "state": "watching the gateway",
+                "emoji": {
+                    "name": "catpat",
+                    "id": "456",
+                    "animated": true
+                },
+                "created_at": 1_723_898_413_556_u64
+            }],
+            "client_status": {
+                "desktop": "online",
+                "web": "idle"
+            }
+        });
+
+        let presence: Presence = from_value(value).unwrap();
+        assert_eq!(presence.activities.len(), 1);
+
+        let activity = &presence.activities[0];
+        assert_eq!(activity.kind, ActivityType::Custom);
+        assert_eq!(activity.state.as_deref(), Some("watching the gateway"));
+        assert_eq!(activity.emoji.as_ref().unwrap().id, Some(EmojiId::new(456)));
+
+        let client_status = presence.client_status.unwrap();
+        assert_eq!(client_status.desktop, Some(OnlineStatus::Online));
+        assert_eq!(client_status.web, Some(OnlineStatus::Idle));
+        assert_eq!(client_status.mobile, None);
+    }
+
+    #[test]
+    fn activity_buttons() {
+        // Bots only receive the labels of an activity's buttons, while some payloads carry the
+        // full button objects. Both forms must deserialize.
+        let value = json!({
+            "type": 0,
+            "name": "label form",
+            "buttons": ["one", "two"],
+            "created_at": 1_723_898_413_556_u64
+        });
+
+        let activity: Activity = from_value(value).unwrap();
+        let [first, second] = activity.buttons.as_slice() else {
+            panic!("expected two buttons: {:?}", activity.buttons);
+        };
+        assert_eq!(first.label, "one");
+        assert_eq!(first.url, "");
+        assert_eq!(second.label, "two");
+
+        let value = json!({
+            "type": 0,
+            "name": "object form",
+            "buttons": [{"label": "one", "url": "https://example.com"}],
+            "created_at": 1_723_898_413_556_u64
+        });
+
+        let activity: Activity = from_value(value).unwrap();
+        let [button] = activity.buttons.as_slice() else {
+            panic!("expected one button: {:?}", activity.buttons);
+        };
+        assert_eq!(button.label, "one");
+        assert_eq!(button.url, "https://example.com");
+    }
+}
@@ -70,22 +70,32 @@ impl Action {
     pub fn from_value(value: u8) -> Action {
         match value {
             1 => Action::GuildUpdate,
-            10..=12 => Action::Channel(unsafe { transmute(value) }),
-            13..=15 => Action::ChannelOverwrite(unsafe { transmute(value) }),
-            20..=28 => Action::Member(unsafe { transmute(value) }),
-            30..=32 => Action::Role(unsafe { transmute(value) }),
-            40..=42 => Action::Invite(unsafe { transmute(value) }),
-            50..=52 => Action::Webhook(unsafe { transmute(value) }),
-            60..=62 => Action::Emoji(unsafe { transmute(value) }),
-            72..=75 => Action::Message(unsafe { transmute(value) }),
-            80..=82 => Action::Integration(unsafe { transmute(value) }),
-            83..=85 => Action::StageInstance(unsafe { transmute(value) }),
-            90..=92 => Action::Sticker(unsafe { transmute(value) }),
-            100..=102 => Action::ScheduledEvent(unsafe { transmute(value) }),
-            110..=112 => Action::Thread(unsafe { transmute(value) }),
-            140..=145 => Action::AutoMod(unsafe { transmute(value) }),
-            150..=151 => Action::CreatorMonetization(unsafe { transmute(value) }),
-            192..=193 => Action::VoiceChannelStatus(unsafe { transmute(value) }),
+            10..=12 => Action::Channel(unsafe { transmute::<u8, ChannelAction>(value) }),
+            13..=15 => {
+                Action::ChannelOverwrite(unsafe { transmute::<u8, ChannelOverwriteAction>(value) })
+            },
+            20..=28 => Action::Member(unsafe { transmute::<u8, MemberAction>(value) }),
+            30..=32 => Action::Role(unsafe { transmute::<u8, RoleAction>(value) }),
+            40..=42 => Action::Invite(unsafe { transmute::<u8, InviteAction>(value) }),
+            50..=52 => Action::Webhook(unsafe { transmute::<u8, WebhookAction>(value) }),
+            60..=62 => Action::Emoji(unsafe { transmute::<u8, EmojiAction>(value) }),
+            72..=75 => Action::Message(unsafe { transmute::<u8, MessageAction>(value) }),
+            80..=82 => Action::Integration(unsafe { transmute::<u8, IntegrationAction>(value) }),
+            83..=85 => {
+                Action::StageInstance(unsafe { transmute::<u8, StageInstanceAction>(value) })
+            },
+            90..=92 => Action::Sticker(unsafe { transmute::<u8, StickerAction>(value) }),
+            100..=102 => {
+                Action::ScheduledEvent(unsafe { transmute::<u8, ScheduledEventAction>(value) })
+            },
+            110..=112 => Action::Thread(unsafe { transmute::<u8, ThreadAction>(value) }),
+            140..=145 => Action::AutoMod(unsafe { transmute::<u8, AutoModAction>(value) }),
+            150..=151 => Action::CreatorMonetization(unsafe {
+                transmute::<u8, CreatorMonetizationAction>(value)
+            }),
+            192..=193 => Action::VoiceChannelStatus(unsafe {
+                transmute::<u8, VoiceChannelStatusAction>(value)
+            }),
             _ => Action::Unknown(value),
         }
     }
@@ -346,7 +356,10 @@ pub struct AuditLogEntry {
     /// What was the reasoning by doing an action on a target? If there was one.
     pub reason: Option<String>,
     /// The user that did this action on a target.
-    pub user_id: UserId,
+    ///
+    /// **Note**: May be [`None`] for entries generated by Discord itself, such as automatic
+    /// moderation actions.
+    pub user_id: Option<UserId>,
     /// What changes were made.
     pub changes: Option<Vec<Change>>,
     /// The id of this entry.
@@ -399,6 +412,24 @@ pub struct Options {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn entry_nullable_user_id() {
+        let value = json!({
+            "target_id": "123",
+            "action_type": 20,
+            "reason": null,
+            "user_id": null,
+            "changes": null,
+            "id": "456",
+            "options": null,
+        });
+
+        let entry: AuditLogEntry = from_value(value).unwrap();
+        assert!(matches!(entry.action, Action::Member(MemberAction::Kick)));
+        assert_eq!(entry.user_id, None);
+    }
 
     #[test]
     fn action_value() {
@@ -190,7 +190,9 @@ impl Serialize for Trigger {
             },
             Self::MentionSpam {
                 mention_total_limit,
-            } => trigger.metadata.mention_total_limit = Some(*mention_total_limit),
+            } => {
+                trigger.metadata.mention_total_limit = Some(*mention_total_limit);
+            },
             Self::Spam | Self::Unknown(_) => {},
         }
         trigger.serialize(serializer)
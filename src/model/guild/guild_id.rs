@@ -14,6 +14,7 @@ use crate::builder::{
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildMemberVerification,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
@@ -178,8 +179,8 @@ impl GuildId {
         builder.execute(cache_http, (self, user_id.into())).await
     }
 
-    /// Ban a [`User`] from the guild, deleting a number of days' worth of messages (`dmd`) between
-    /// the range 0 and 7.
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of messages (`dms`)
+    /// between the range 0 and 604800 (7 days).
     ///
     /// Refer to the documentation for [`Guild::ban`] for more information.
     ///
@@ -197,22 +198,27 @@ impl GuildId {
     /// # let http: Http = unimplemented!();
     /// # let user = UserId::new(1);
     /// // assuming a `user` has already been bound
-    /// let _ = GuildId::new(81384788765712384).ban(&http, user, 4).await;
+    /// let _ = GuildId::new(81384788765712384).ban(&http, user, 4 * 86400).await;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of days' worth of messages
-    /// to delete is over the maximum.
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of seconds' worth of
+    /// messages to delete is over the maximum.
     ///
     /// Also can return [`Error::Http`] if the current user lacks permission.
     ///
     /// [Ban Members]: Permissions::BAN_MEMBERS
     #[inline]
-    pub async fn ban(self, http: impl AsRef<Http>, user: impl Into<UserId>, dmd: u8) -> Result<()> {
-        self._ban(http, user.into(), dmd, None).await
+    pub async fn ban(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        dms: u32,
+    ) -> Result<()> {
+        self._ban(http, user.into(), dms, None).await
     }
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`Self::ban`] to further
@@ -227,21 +233,21 @@ impl GuildId {
         self,
         http: impl AsRef<Http>,
         user: impl Into<UserId>,
-        dmd: u8,
+        dms: u32,
         reason: impl AsRef<str>,
     ) -> Result<()> {
-        self._ban(http, user.into(), dmd, Some(reason.as_ref())).await
+        self._ban(http, user.into(), dms, Some(reason.as_ref())).await
     }
 
     async fn _ban(
         self,
         http: impl AsRef<Http>,
         user: UserId,
-        dmd: u8,
+        dms: u32,
         reason: Option<&str>,
     ) -> Result<()> {
-        if dmd > 7 {
-            return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
+        if dms > 604800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(dms)));
         }
 
         if let Some(reason) = reason {
@@ -250,7 +256,23 @@ impl GuildId {
             }
         }
 
-        http.as_ref().ban_user(self, user, dmd, reason).await
+        http.as_ref().ban_user(self, user, dms, reason).await
+    }
+
+    /// Gets the [`Ban`] entry of a banned [`User`] in the guild, containing the reason they were
+    /// banned, if one was given.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the user is not
+    /// banned.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_info(self, http: impl AsRef<Http>, user: impl Into<UserId>) -> Result<Ban> {
+        http.as_ref().get_ban(self, user.into()).await
     }
 
     /// Gets a list of the guild's bans, with additional options and filtering. See
@@ -373,10 +395,12 @@ impl GuildId {
         http: impl AsRef<Http>,
         name: &str,
         image: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
         let map = json!({
             "name": name,
             "image": image,
+            "roles": roles,
         });
 
         http.as_ref().create_emoji(self, &map, None).await
@@ -624,9 +648,11 @@ impl GuildId {
         http: impl AsRef<Http>,
         emoji_id: impl Into<EmojiId>,
         name: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
         let map = json!({
             "name": name,
+            "roles": roles,
         });
 
         http.as_ref().edit_emoji(self, emoji_id.into(), &map, None).await
@@ -670,6 +696,47 @@ impl GuildId {
         builder.execute(cache_http, (self, user_id.into())).await
     }
 
+    /// Edits the guild's membership screening form.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_member_verification(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildMemberVerification<'_>,
+    ) -> Result<GuildMemberVerification> {
+        builder.execute(cache_http, self).await
+    }
+
+    /// Edits the guild's incident actions, pausing invites and/or direct messages for up to 24
+    /// hours. Pass [`None`] to resume invites or direct messages. Returns the updated incidents
+    /// data on success.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Administrator] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a timestamp is more
+    /// than 24 hours in the future.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Administrator]: Permissions::ADMINISTRATOR
+    pub async fn edit_incident_actions(
+        self,
+        http: impl AsRef<Http>,
+        invites_disabled_until: Option<Timestamp>,
+        dms_disabled_until: Option<Timestamp>,
+    ) -> Result<IncidentsData> {
+        http.as_ref()
+            .edit_guild_incident_actions(self, invites_disabled_until, dms_disabled_until)
+            .await
+    }
+
     /// Edits the guild's MFA level. Returns the new level on success.
     ///
     /// Requires guild ownership.
@@ -683,10 +750,7 @@ impl GuildId {
         mfa_level: MfaLevel,
         audit_log_reason: Option<&str>,
     ) -> Result<MfaLevel> {
-        let value = json!({
-            "level": mfa_level,
-        });
-        http.as_ref().edit_guild_mfa_level(self, &value, audit_log_reason).await
+        http.as_ref().edit_guild_mfa_level(self, mfa_level, audit_log_reason).await
     }
 
     /// Edits the current user's nickname for the guild.
@@ -1233,15 +1297,51 @@ impl GuildId {
         http.as_ref().edit_guild_channel_positions(self, &items).await
     }
 
+    /// Re-orders the roles of the guild, with a single PATCH request.
+    ///
+    /// Accepts an iterator of a tuple of the role ID to modify and its new position.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    #[inline]
+    pub async fn reorder_roles(
+        self,
+        http: impl AsRef<Http>,
+        roles: impl IntoIterator<Item = (RoleId, u64)>,
+    ) -> Result<Vec<Role>> {
+        let items = roles
+            .into_iter()
+            .map(|(id, pos)| {
+                json!({
+                    "id": id,
+                    "position": pos,
+                })
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        http.as_ref().edit_role_positions(self, &items, None).await
+    }
+
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname starts with a
     /// provided string.
     ///
     /// Optionally pass in the `limit` to limit the number of results. Minimum value is 1, maximum
     /// and default value is 1000.
     ///
+    /// **Note**: Queries are performed by the API and work without the [`GUILD_MEMBERS`] intent
+    /// or a filled member cache.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`GUILD_MEMBERS`]: crate::model::gateway::GatewayIntents::GUILD_MEMBERS
     #[inline]
     pub async fn search_members(
         self,
@@ -1426,9 +1526,11 @@ impl GuildId {
         http.as_ref().remove_ban(self, user_id.into(), None).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity URL, returning its code and how many times it has been
+    /// used.
     ///
-    /// **Note**: Requires the [Manage Guild] permission.
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the `VANITY_URL`
+    /// feature.
     ///
     /// # Errors
     ///
@@ -1437,7 +1539,7 @@ impl GuildId {
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         http.as_ref().get_guild_vanity_url(self).await
     }
 
@@ -1610,6 +1712,18 @@ impl GuildId {
         http.as_ref().get_guild_command_permissions(self, command_id).await
     }
 
+    /// Gets the guild's membership screening form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild does not have membership screening enabled.
+    pub async fn get_member_verification(
+        self,
+        http: impl AsRef<Http>,
+    ) -> Result<GuildMemberVerification> {
+        http.as_ref().get_guild_member_verification(self).await
+    }
+
     /// Get the guild welcome screen.
     ///
     /// # Errors
@@ -1664,7 +1778,7 @@ impl From<PartialGuild> for GuildId {
     }
 }
 
-impl<'a> From<&'a PartialGuild> for GuildId {
+impl From<&PartialGuild> for GuildId {
     /// Gets the Id of a partial guild.
     fn from(guild: &PartialGuild) -> GuildId {
         guild.id
@@ -1678,7 +1792,7 @@ impl From<GuildInfo> for GuildId {
     }
 }
 
-impl<'a> From<&'a GuildInfo> for GuildId {
+impl From<&GuildInfo> for GuildId {
     /// Gets the Id of Guild information struct.
     fn from(guild_info: &GuildInfo) -> GuildId {
         guild_info.id
@@ -1692,7 +1806,7 @@ impl From<InviteGuild> for GuildId {
     }
 }
 
-impl<'a> From<&'a InviteGuild> for GuildId {
+impl From<&InviteGuild> for GuildId {
     /// Gets the Id of Invite Guild struct.
     fn from(invite_guild: &InviteGuild) -> GuildId {
         invite_guild.id
@@ -1706,7 +1820,7 @@ impl From<Guild> for GuildId {
     }
 }
 
-impl<'a> From<&'a Guild> for GuildId {
+impl From<&Guild> for GuildId {
     /// Gets the Id of Guild.
     fn from(live_guild: &Guild) -> GuildId {
         live_guild.id
@@ -1720,7 +1834,7 @@ impl From<WebhookGuild> for GuildId {
     }
 }
 
-impl<'a> From<&'a WebhookGuild> for GuildId {
+impl From<&WebhookGuild> for GuildId {
     /// Gets the Id of Webhook Guild struct.
     fn from(webhook_guild: &WebhookGuild) -> GuildId {
         webhook_guild.id
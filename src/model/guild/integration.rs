@@ -10,23 +10,39 @@ use crate::model::Timestamp;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Integration {
+    /// The integration's Id.
     pub id: IntegrationId,
+    /// The integration's name.
     pub name: String,
+    /// The integration's type: `twitch`, `youtube`, `discord` or `guild_subscription`.
     #[serde(rename = "type")]
     pub kind: String,
+    /// Whether this integration is enabled.
     pub enabled: bool,
+    /// Whether this integration is syncing. Not provided for bot integrations.
     pub syncing: Option<bool>,
+    /// The Id of the role that this integration uses for subscribers.
     pub role_id: Option<RoleId>,
+    /// Whether emoticons should be synced for this integration. Twitch only.
     pub enable_emoticons: Option<bool>,
+    /// The behaviour of expiring subscribers. Not provided for bot integrations.
     #[serde(rename = "expire_behavior")]
     pub expire_behaviour: Option<IntegrationExpireBehaviour>,
+    /// The grace period in days before expiring subscribers.
     pub expire_grace_period: Option<u64>,
+    /// The user for this integration.
     pub user: Option<User>,
+    /// The integration's account information.
     pub account: IntegrationAccount,
+    /// When this integration was last synced. Not provided for bot integrations.
     pub synced_at: Option<Timestamp>,
+    /// How many subscribers this integration has.
     pub subscriber_count: Option<u64>,
+    /// Whether this integration has been revoked.
     pub revoked: Option<bool>,
+    /// The bot or OAuth2 application for discord integrations.
     pub application: Option<IntegrationApplication>,
+    /// The scopes the application has been authorized for.
     pub scopes: Option<Vec<Scope>>,
     /// Only present in [`IntegrationCreateEvent`] and [`IntegrationUpdateEvent`].
     pub guild_id: Option<GuildId>,
@@ -61,7 +77,9 @@ impl From<Integration> for IntegrationId {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct IntegrationAccount {
+    /// The Id of the account.
     pub id: String,
+    /// The name of the account.
     pub name: String,
 }
 
@@ -72,9 +90,14 @@ pub struct IntegrationAccount {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct IntegrationApplication {
+    /// The Id of the application.
     pub id: ApplicationId,
+    /// The name of the application.
     pub name: String,
+    /// The icon hash of the application.
     pub icon: Option<ImageHash>,
+    /// The description of the application.
     pub description: String,
+    /// The bot associated with this application.
     pub bot: Option<User>,
 }
@@ -32,6 +32,9 @@ pub struct Member {
     pub nick: Option<String>,
     /// The guild avatar hash
     pub avatar: Option<ImageHash>,
+    /// The guild banner hash
+    #[serde(default)]
+    pub banner: Option<ImageHash>,
     /// Vector of Ids of [`Role`]s given to the member.
     pub roles: Vec<RoleId>,
     /// Timestamp representing the date when the member joined.
@@ -116,20 +119,20 @@ impl Member {
         Ok(())
     }
 
-    /// Ban a [`User`] from the guild, deleting a number of days' worth of messages (`dmd`) between
-    /// the range 0 and 7.
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of messages (`dms`)
+    /// between the range 0 and 604800 (7 days).
     ///
     /// **Note**: Requires the [Ban Members] permission.
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the `dmd` is greater than 7. Can also
-    /// return [`Error::Http`] if the current user lacks permission to ban this member.
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the `dms` is greater than 604800.
+    /// Can also return [`Error::Http`] if the current user lacks permission to ban this member.
     ///
     /// [Ban Members]: Permissions::BAN_MEMBERS
     #[inline]
-    pub async fn ban(&self, http: impl AsRef<Http>, dmd: u8) -> Result<()> {
-        self.ban_with_reason(http, dmd, "").await
+    pub async fn ban(&self, http: impl AsRef<Http>, dms: u32) -> Result<()> {
+        self.ban_with_reason(http, dms, "").await
     }
 
     /// Ban the member from the guild with a reason. Refer to [`Self::ban`] to further
@@ -143,10 +146,10 @@ impl Member {
     pub async fn ban_with_reason(
         &self,
         http: impl AsRef<Http>,
-        dmd: u8,
+        dms: u32,
         reason: impl AsRef<str>,
     ) -> Result<()> {
-        self.guild_id.ban_with_reason(http, self.user.id, dmd, reason).await
+        self.guild_id.ban_with_reason(http, self.user.id, dms, reason).await
     }
 
     /// Determines the member's colour.
@@ -304,6 +307,30 @@ impl Member {
         highest
     }
 
+    /// Returns whether the member outranks `other` in the guild's role hierarchy, meaning that
+    /// moderation actions such as kicking, banning or editing the roles of `other` can succeed as
+    /// far as the hierarchy is concerned. The permissions required for those actions still apply.
+    ///
+    /// The guild owner outranks everyone. Members with the same highest role, and members
+    /// targeting themselves, cannot moderate each other.
+    ///
+    /// Returns `false` if the members are from different guilds, or if the guild or one of the
+    /// members is not present in the cache.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn can_moderate(&self, cache: impl AsRef<Cache>, other: &Member) -> bool {
+        if self.guild_id != other.guild_id {
+            return false;
+        }
+
+        let cache = cache.as_ref();
+        let Some(guild) = cache.guild(self.guild_id) else {
+            return false;
+        };
+
+        guild.greater_member_hierarchy(cache, self.user.id, other.user.id) == Some(self.user.id)
+    }
+
     /// Kick the member from the guild.
     ///
     /// **Note**: Requires the [Kick Members] permission.
@@ -440,6 +467,25 @@ impl Member {
         Ok(guild.member_permissions(self))
     }
 
+    /// Returns the member's permissions in the given channel, applying the channel's permission
+    /// overwrites on top of the guild-level permissions.
+    ///
+    /// See [`Guild::user_permissions_in`] for details on the calculation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the guild the member's in could not be found in
+    /// the cache.
+    #[cfg(feature = "cache")]
+    pub fn permissions_in(
+        &self,
+        cache: impl AsRef<Cache>,
+        channel: &GuildChannel,
+    ) -> Result<Permissions> {
+        let guild = cache.as_ref().guild(self.guild_id).ok_or(ModelError::GuildNotFound)?;
+        Ok(guild.user_permissions_in(channel, self))
+    }
+
     /// Removes a [`Role`] from the member.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
@@ -519,6 +565,25 @@ impl Member {
         avatar_url(Some(self.guild_id), self.user.id, self.avatar.as_ref())
     }
 
+    /// Returns the formatted URL of the member's per guild banner, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the member has a GIF banner.
+    #[inline]
+    #[must_use]
+    pub fn banner_url(&self) -> Option<String> {
+        self.banner.as_ref().map(|banner| {
+            let ext = if banner.is_animated() { "gif" } else { "webp" };
+
+            cdn!(
+                "/guilds/{}/users/{}/banners/{}.{}?size=1024",
+                self.guild_id,
+                self.user.id,
+                banner,
+                ext
+            )
+        })
+    }
+
     /// Retrieves the URL to the current member's avatar, falling back to the user's avatar, then
     /// default avatar if needed.
     ///
@@ -601,6 +666,7 @@ impl From<PartialMember> for Member {
             user: partial.user.unwrap_or_default(),
             nick: partial.nick,
             avatar: None,
+            banner: None,
             roles: partial.roles,
             joined_at: partial.joined_at,
             premium_since: partial.premium_since,
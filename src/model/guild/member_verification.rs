@@ -0,0 +1,51 @@
+use crate::model::prelude::*;
+
+/// The membership screening form of a guild, which members with [`Member::pending`] set must
+/// complete before they can interact with the guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#membership-screening-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildMemberVerification {
+    /// When the form was last modified.
+    pub version: Option<Timestamp>,
+    /// The steps of the form.
+    pub form_fields: Vec<VerificationFormField>,
+    /// The server description shown in the form.
+    pub description: Option<String>,
+}
+
+/// A single step of a [`GuildMemberVerification`] form.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#membership-screening-object-membership-screening-field-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VerificationFormField {
+    /// The type of the field.
+    #[serde(rename = "field_type")]
+    pub kind: VerificationFormFieldType,
+    /// The title of the field.
+    pub label: String,
+    /// The list of rules, if the field is of type [`VerificationFormFieldType::Terms`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    /// Whether the field is required to complete the form.
+    pub required: bool,
+}
+
+/// The type of a [`VerificationFormField`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#membership-screening-object-membership-screening-field-types).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum VerificationFormFieldType {
+    /// The server rules, which members must agree to.
+    #[serde(rename = "TERMS")]
+    Terms,
+    /// Variant value is unknown.
+    #[serde(other)]
+    Unknown,
+}
@@ -7,6 +7,8 @@ mod guild_id;
 mod guild_preview;
 mod integration;
 mod member;
+mod member_verification;
+mod onboarding;
 mod partial_guild;
 mod premium_tier;
 mod role;
@@ -17,6 +19,8 @@ mod welcome_screen;
 #[cfg(feature = "model")]
 use std::borrow::Cow;
 
+#[cfg(feature = "model")]
+use futures::stream::Stream;
 #[cfg(feature = "model")]
 use tracing::{error, warn};
 
@@ -25,6 +29,8 @@ pub use self::guild_id::*;
 pub use self::guild_preview::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::member_verification::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
@@ -41,6 +47,7 @@ use crate::builder::{
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildMemberVerification,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
@@ -90,6 +97,26 @@ pub struct AfkMetadata {
     pub afk_timeout: AfkTimeout,
 }
 
+/// The incidents data of a [`Guild`], describing which of Discord's native raid protections are
+/// currently active.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#incidents-data-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct IncidentsData {
+    /// When invites get enabled again, if they are currently paused.
+    pub invites_disabled_until: Option<Timestamp>,
+    /// When direct messages get enabled again, if they are currently paused.
+    pub dms_disabled_until: Option<Timestamp>,
+    /// When the direct message spam was detected, if any was.
+    #[serde(default)]
+    pub dm_spam_detected_at: Option<Timestamp>,
+    /// When the raid was detected, if any was.
+    #[serde(default)]
+    pub raid_detected_at: Option<Timestamp>,
+}
+
 /// Information about a Discord guild, such as channels, emojis, etc.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object) plus
@@ -234,6 +261,15 @@ pub struct Guild {
     pub stickers: HashMap<StickerId, Sticker>,
     /// Whether the guild has the boost progress bar enabled
     pub premium_progress_bar_enabled: bool,
+    /// The id of the channel where admins and moderators of Community guilds receive safety alerts
+    /// from Discord.
+    ///
+    /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
+    #[serde(default)]
+    pub safety_alerts_channel_id: Option<ChannelId>,
+    /// The incidents data for the guild, if any incident actions are currently active.
+    #[serde(default)]
+    pub incidents_data: Option<IncidentsData>,
 
     // =======
     // From here on, all fields are from Guild Create Event's extra fields (see Discord docs)
@@ -450,8 +486,8 @@ impl Guild {
         None
     }
 
-    /// Ban a [`User`] from the guild, deleting a number of days' worth of messages (`dmd`) between
-    /// the range 0 and 7.
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of messages (`dms`)
+    /// between the range 0 and 604800 (7 days).
     ///
     /// Refer to the documentation for [`Guild::ban`] for more information.
     ///
@@ -463,13 +499,13 @@ impl Guild {
     ///
     /// ```rust,ignore
     /// // assumes a `user` and `guild` have already been bound
-    /// let _ = guild.ban(user, 4);
+    /// let _ = guild.ban(user, 4 * 86400);
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of days' worth of messages
-    /// to delete is over the maximum.
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of seconds' worth of
+    /// messages to delete is over the maximum.
     ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// does not have permission to perform bans, or may return a [`ModelError::Hierarchy`] if the
@@ -483,9 +519,9 @@ impl Guild {
         &self,
         cache_http: impl CacheHttp,
         user: impl Into<UserId>,
-        dmd: u8,
+        dms: u32,
     ) -> Result<()> {
-        self._ban_with_reason(cache_http, user.into(), dmd, "").await
+        self._ban_with_reason(cache_http, user.into(), dms, "").await
     }
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`Self::ban`] to further
@@ -500,17 +536,17 @@ impl Guild {
         &self,
         cache_http: impl CacheHttp,
         user: impl Into<UserId>,
-        dmd: u8,
+        dms: u32,
         reason: impl AsRef<str>,
     ) -> Result<()> {
-        self._ban_with_reason(cache_http, user.into(), dmd, reason.as_ref()).await
+        self._ban_with_reason(cache_http, user.into(), dms, reason.as_ref()).await
     }
 
     async fn _ban_with_reason(
         &self,
         cache_http: impl CacheHttp,
         user: UserId,
-        dmd: u8,
+        dms: u32,
         reason: &str,
     ) -> Result<()> {
         #[cfg(feature = "cache")]
@@ -522,7 +558,7 @@ impl Guild {
             }
         }
 
-        self.id.ban_with_reason(cache_http.http(), user, dmd, reason).await
+        self.id.ban_with_reason(cache_http.http(), user, dms, reason).await
     }
 
     /// Returns the formatted URL of the guild's banner image, if one exists.
@@ -715,8 +751,9 @@ impl Guild {
         http: impl AsRef<Http>,
         name: &str,
         image: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
-        self.id.create_emoji(http, name, image).await
+        self.id.create_emoji(http, name, image, roles).await
     }
 
     /// Creates an integration for the guild.
@@ -925,10 +962,10 @@ impl Guild {
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
-    pub async fn create_sticker<'a>(
+    pub async fn create_sticker(
         &self,
         cache_http: impl CacheHttp,
-        builder: CreateSticker<'a>,
+        builder: CreateSticker<'_>,
     ) -> Result<Sticker> {
         self.id.create_sticker(cache_http.http(), builder).await
     }
@@ -1129,8 +1166,9 @@ impl Guild {
         http: impl AsRef<Http>,
         emoji_id: impl Into<EmojiId>,
         name: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
-        self.id.edit_emoji(http, emoji_id, name).await
+        self.id.edit_emoji(http, emoji_id, name, roles).await
     }
 
     /// Edits the properties a guild member, such as muting or nicknaming them. Returns the new
@@ -1314,6 +1352,23 @@ impl Guild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Edits the guild's membership screening form.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_member_verification(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildMemberVerification<'_>,
+    ) -> Result<GuildMemberVerification> {
+        self.id.edit_member_verification(cache_http, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1664,6 +1719,7 @@ impl Guild {
     ///
     /// For the `prefix` "zey" and the unsorted result:
     /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
+    ///
     /// It would be sorted:
     /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
     ///
@@ -1720,6 +1776,7 @@ impl Guild {
     ///
     /// If the substring is "yla", following results are possible:
     /// - "zeyla", "meiyla", "yladenisyla"
+    ///
     /// If 'case_sensitive' is false, the following are not found:
     /// - "zeYLa", "meiyLa", "LYAdenislyA"
     ///
@@ -1729,6 +1786,7 @@ impl Guild {
     ///
     /// For the `substring` "zey" and the unsorted result:
     /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
+    ///
     /// It would be sorted:
     /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
     ///
@@ -1781,6 +1839,7 @@ impl Guild {
     ///
     /// If the substring is "yla", following results are possible:
     /// - "zeyla", "meiyla", "yladenisyla"
+    ///
     /// If 'case_sensitive' is false, the following are not found:
     /// - "zeYLa", "meiyLa", "LYAdenislyA"
     ///
@@ -1789,6 +1848,7 @@ impl Guild {
     ///
     /// For the `substring` "zey" and the unsorted result:
     /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
+    ///
     /// It would be sorted:
     /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
     ///
@@ -1827,6 +1887,7 @@ impl Guild {
     ///
     /// If the substring is "yla", following results are possible:
     /// - "zeyla", "meiyla", "yladenisyla"
+    ///
     /// If 'case_sensitive' is false, the following are not found:
     /// - "zeYLa", "meiyLa", "LYAdenislyA"
     ///
@@ -1835,6 +1896,7 @@ impl Guild {
     ///
     /// For the `substring` "zey" and the unsorted result:
     /// - "azey", "zey", "zeyla", "zeylaa", "zeyzeyzey"
+    ///
     /// It would be sorted:
     /// - "zey", "azey", "zeyla", "zeylaa", "zeyzeyzey"
     ///
@@ -1957,7 +2019,7 @@ impl Guild {
             }
         }
 
-        calculate_permissions(CalculatePermissions {
+        let mut permissions = calculate_permissions(CalculatePermissions {
             is_guild_owner: member.user.id == guild_owner_id,
             everyone_permissions: if let Some(role) = guild_roles.get(&RoleId::new(guild_id.get()))
             {
@@ -1987,7 +2049,17 @@ impl Guild {
             roles_deny_overwrites,
             member_allow_overwrites,
             member_deny_overwrites,
-        })
+        });
+
+        // A timed-out member is restricted to reading, unless they are an administrator or the
+        // guild owner.
+        if !permissions.contains(Permissions::ADMINISTRATOR)
+            && member.communication_disabled_until.is_some_and(|until| until > Timestamp::now())
+        {
+            permissions &= Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+        }
+
+        permissions
     }
 
     /// Calculate a [`Role`]'s permissions in a given channel in the guild.
@@ -2272,6 +2344,14 @@ impl Guild {
         self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
     }
 
+    /// Returns the formatted URL of the guild's discovery splash image, if one exists.
+    #[must_use]
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        self.discovery_splash
+            .as_ref()
+            .map(|splash| cdn!("/discovery-splashes/{}/{}.webp?size=4096", self.id, splash))
+    }
+
     /// Starts an integration sync for the given integration Id.
     ///
     /// Requires the [Manage Guild] permission.
@@ -2358,7 +2438,7 @@ impl Guild {
     /// Will return [`Error::Http`] if the current user is lacking permissions. Can also return an
     /// [`Error::Json`] if there is an error deserializing the API response.
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(http).await
     }
 
@@ -2587,6 +2667,19 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// A [`Guild`]'s vanity invite, returned from [`Http::get_guild_vanity_url`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildVanityUrl {
+    /// The vanity invite code, if one is set.
+    pub code: Option<String>,
+    /// The number of times the vanity invite has been used.
+    pub uses: u64,
+}
+
 /// Variant of [`Guild`] returned from [`Http::get_guilds`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object),
@@ -2611,6 +2704,16 @@ pub struct GuildInfo {
     pub permissions: Permissions,
     /// See [`Guild::features`].
     pub features: Vec<String>,
+    /// Approximate number of members in this guild.
+    ///
+    /// **Note**: Only present when fetched via [`Http::get_guilds_with_counts`].
+    #[serde(default)]
+    pub approximate_member_count: Option<u64>,
+    /// Approximate number of non-offline members in this guild.
+    ///
+    /// **Note**: Only present when fetched via [`Http::get_guilds_with_counts`].
+    #[serde(default)]
+    pub approximate_presence_count: Option<u64>,
 }
 
 #[cfg(feature = "model")]
@@ -2624,6 +2727,90 @@ impl GuildInfo {
     }
 }
 
+/// A helper class for iterating over the guilds of the current user.
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct GuildsIter<H: AsRef<Http>> {
+    http: H,
+    buffer: Vec<GuildInfo>,
+    after: Option<GuildId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> GuildsIter<H> {
+    fn new(http: H) -> GuildsIter<H> {
+        GuildsIter {
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of guilds.
+    ///
+    /// This drops any guilds that were currently in the buffer, so it should only be called when
+    /// `self.buffer` is empty. Additionally, this updates `self.after` so that the next call does
+    /// not return duplicate items. If there are no more guilds to be fetched, then this marks
+    /// `self.after` as None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of guilds to fetch
+        let grab_size: u64 = 200;
+
+        let target = self.after.map(crate::http::GuildPagination::After);
+        self.buffer = self.http.as_ref().get_guilds(target, Some(grab_size)).await?;
+
+        // Get the last guild. If shorter than 200, there are no more results anyway
+        self.after = self.buffer.get(grab_size as usize - 1).map(|guild| guild.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the guilds of the current user.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Http::get_guilds`]. A buffer of
+    /// at most 200 guilds is used to reduce the number of calls necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    /// use serenity::model::guild::GuildsIter;
+    ///
+    /// let mut guilds = GuildsIter::<&Http>::stream(&http).boxed();
+    /// while let Some(guild_result) = guilds.next().await {
+    ///     match guild_result {
+    ///         Ok(guild) => println!("{} is {}", guild.id, guild.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn stream(http: H) -> impl Stream<Item = Result<GuildInfo>> {
+        let init_state = GuildsIter::new(http);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
 #[cfg(feature = "model")]
 impl InviteGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::model::prelude::*;
+
+/// The onboarding flow of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildOnboarding {
+    /// The Id of the guild this onboarding flow is part of.
+    pub guild_id: GuildId,
+    /// The prompts shown during onboarding and in the Channels & Roles tab.
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The Ids of the channels that members get opted into automatically.
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether onboarding is enabled in the guild.
+    pub enabled: bool,
+    /// The criteria mode used to validate the onboarding configuration.
+    pub mode: OnboardingMode,
+}
+
+/// A prompt shown during onboarding and in the Channels & Roles tab.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    pub id: OnboardingPromptId,
+    /// The type of the prompt.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options available within the prompt.
+    pub options: Vec<OnboardingPromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether users are limited to selecting one option for the prompt.
+    pub single_select: bool,
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub required: bool,
+    /// Whether the prompt is present in the onboarding flow. If `false`, the prompt will only
+    /// appear in the Channels & Roles tab.
+    pub in_onboarding: bool,
+}
+
+/// An option available within an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct OnboardingPromptOption {
+    /// The Id of the prompt option.
+    pub id: OnboardingPromptOptionId,
+    /// The Ids of the channels a member is added to when the option is selected.
+    pub channel_ids: Vec<ChannelId>,
+    /// The Ids of the roles assigned to a member when the option is selected.
+    pub role_ids: Vec<RoleId>,
+    /// The emoji shown for the option, if there is one.
+    pub emoji: Option<OnboardingPromptOptionEmoji>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option.
+    pub description: Option<String>,
+}
+
+// Manual impl needed as Discord returns a nested emoji object, but expects the flattened
+// emoji_id, emoji_name, and emoji_animated fields when creating or updating an option.
+impl<'de> Deserialize<'de> for OnboardingPromptOption {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct EmojiHelper {
+            id: Option<EmojiId>,
+            name: Option<String>,
+            #[serde(default)]
+            animated: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct Helper {
+            id: OnboardingPromptOptionId,
+            channel_ids: Vec<ChannelId>,
+            role_ids: Vec<RoleId>,
+            emoji: Option<EmojiHelper>,
+            title: String,
+            description: Option<String>,
+        }
+
+        let Helper {
+            id,
+            channel_ids,
+            role_ids,
+            emoji,
+            title,
+            description,
+        } = Helper::deserialize(deserializer)?;
+
+        let emoji = emoji.and_then(|emoji| match (emoji.id, emoji.name) {
+            (Some(id), name) => Some(OnboardingPromptOptionEmoji::Custom {
+                id,
+                name,
+                animated: emoji.animated,
+            }),
+            (None, Some(name)) => Some(OnboardingPromptOptionEmoji::Unicode(name)),
+            (None, None) => None,
+        });
+
+        Ok(Self {
+            id,
+            channel_ids,
+            role_ids,
+            emoji,
+            title,
+            description,
+        })
+    }
+}
+
+impl Serialize for OnboardingPromptOption {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("OnboardingPromptOption", 8)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("channel_ids", &self.channel_ids)?;
+        s.serialize_field("role_ids", &self.role_ids)?;
+        s.serialize_field("title", &self.title)?;
+        s.serialize_field("description", &self.description)?;
+        let (emoji_id, emoji_name, emoji_animated) = match &self.emoji {
+            Some(OnboardingPromptOptionEmoji::Custom {
+                id,
+                name,
+                animated,
+            }) => (Some(id), name.as_ref(), *animated),
+            Some(OnboardingPromptOptionEmoji::Unicode(name)) => (None, Some(name), false),
+            None => (None, None, false),
+        };
+        s.serialize_field("emoji_id", &emoji_id)?;
+        s.serialize_field("emoji_name", &emoji_name)?;
+        s.serialize_field("emoji_animated", &emoji_animated)?;
+        s.end()
+    }
+}
+
+/// An [`OnboardingPromptOption`] emoji.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum OnboardingPromptOptionEmoji {
+    /// A custom emoji.
+    Custom { id: EmojiId, name: Option<String>, animated: bool },
+    /// A unicode emoji.
+    Unicode(String),
+}
+
+enum_number! {
+    /// The type of an [`OnboardingPrompt`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingPromptType {
+        MultipleChoice = 0,
+        Dropdown = 1,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// The criteria mode used to validate a guild's onboarding configuration.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingMode {
+        /// Only the default channels count towards the onboarding constraints.
+        Default = 0,
+        /// The default channels and questions count towards the onboarding constraints.
+        Advanced = 1,
+        _ => Unknown(u8),
+    }
+}
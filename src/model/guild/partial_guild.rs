@@ -2,16 +2,20 @@ use serde::Serialize;
 
 #[cfg(feature = "model")]
 use crate::builder::{
+    AddMember,
     CreateChannel,
     CreateCommand,
+    CreateScheduledEvent,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildMemberVerification,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
     EditRole,
+    EditScheduledEvent,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
@@ -178,6 +182,15 @@ pub struct PartialGuild {
     pub stickers: HashMap<StickerId, Sticker>,
     /// Whether the guild has the boost progress bar enabled
     pub premium_progress_bar_enabled: bool,
+    /// The id of the channel where admins and moderators of Community guilds receive safety alerts
+    /// from Discord.
+    ///
+    /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
+    #[serde(default)]
+    pub safety_alerts_channel_id: Option<ChannelId>,
+    /// The incidents data for the guild, if any incident actions are currently active.
+    #[serde(default)]
+    pub incidents_data: Option<IncidentsData>,
 }
 
 #[cfg(feature = "model")]
@@ -274,8 +287,8 @@ impl PartialGuild {
         self.id.delete_automod_rule(http, rule_id).await
     }
 
-    /// Ban a [`User`] from the guild, deleting a number of days' worth of messages (`dmd`) between
-    /// the range 0 and 7.
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of messages (`dms`)
+    /// between the range 0 and 604800 (7 days).
     ///
     /// **Note**: Requires the [Ban Members] permission.
     ///
@@ -285,13 +298,13 @@ impl PartialGuild {
     ///
     /// ```rust,ignore
     /// // assumes a `user` and `guild` have already been bound
-    /// let _ = guild.ban(user, 4);
+    /// let _ = guild.ban(user, 4 * 86400);
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of days' worth of messages
-    /// to delete is over the maximum.
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of seconds' worth of
+    /// messages to delete is over the maximum.
     ///
     /// Also may return [`Error::Http`] if the current user lacks permission.
     ///
@@ -301,9 +314,9 @@ impl PartialGuild {
         &self,
         http: impl AsRef<Http>,
         user: impl Into<UserId>,
-        dmd: u8,
+        dms: u32,
     ) -> Result<()> {
-        self.ban_with_reason(http, user, dmd, "").await
+        self.ban_with_reason(http, user, dms, "").await
     }
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`Self::ban`] to further
@@ -318,10 +331,10 @@ impl PartialGuild {
         &self,
         http: impl AsRef<Http>,
         user: impl Into<UserId>,
-        dmd: u8,
+        dms: u32,
         reason: impl AsRef<str>,
     ) -> Result<()> {
-        self.id.ban_with_reason(http, user, dmd, reason).await
+        self.id.ban_with_reason(http, user, dms, reason).await
     }
 
     /// Gets a list of the guild's bans, with additional options and filtering. See
@@ -344,6 +357,24 @@ impl PartialGuild {
         self.id.bans(http, target, limit).await
     }
 
+    /// Adds a [`User`] to this guild with a valid OAuth2 access token.
+    ///
+    /// Returns the created [`Member`] object, or nothing if the user is already a member of the
+    /// guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    #[inline]
+    pub async fn add_member(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        builder: AddMember,
+    ) -> Result<Option<Member>> {
+        self.id.add_member(cache_http, user_id, builder).await
+    }
+
     /// Gets a list of the guild's audit log entries
     ///
     /// **Note**: Requires the [View Audit Log] permission.
@@ -466,8 +497,9 @@ impl PartialGuild {
         http: impl AsRef<Http>,
         name: &str,
         image: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
-        self.id.create_emoji(http, name, image).await
+        self.id.create_emoji(http, name, image, roles).await
     }
 
     /// Creates an integration for the guild.
@@ -647,6 +679,24 @@ impl PartialGuild {
         self.id.create_role(cache_http, builder).await
     }
 
+    /// Creates a new scheduled event in the guild with the data set, if any.
+    ///
+    /// **Note**: Requires the [Create Events] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Events]: Permissions::CREATE_EVENTS
+    pub async fn create_scheduled_event(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateScheduledEvent<'_>,
+    ) -> Result<ScheduledEvent> {
+        self.id.create_scheduled_event(cache_http, builder).await
+    }
+
     /// Creates a new sticker in the guild with the data set, if any.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -657,10 +707,10 @@ impl PartialGuild {
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
-    pub async fn create_sticker<'a>(
+    pub async fn create_sticker(
         &self,
         cache_http: impl CacheHttp,
-        builder: CreateSticker<'a>,
+        builder: CreateSticker<'_>,
     ) -> Result<Sticker> {
         self.id.create_sticker(cache_http, builder).await
     }
@@ -741,6 +791,26 @@ impl PartialGuild {
         self.id.delete_role(http, role_id).await
     }
 
+    /// Deletes a [`ScheduledEvent`] by id from the guild.
+    ///
+    /// **Note**: If the event was created by the current user, requires either [Create Events] or
+    /// the [Manage Events] permission. Otherwise, the [Manage Events] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to delete the scheduled event.
+    ///
+    /// [Create Events]: Permissions::CREATE_EVENTS
+    /// [Manage Events]: Permissions::MANAGE_EVENTS
+    #[inline]
+    pub async fn delete_scheduled_event(
+        &self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+    ) -> Result<()> {
+        self.id.delete_scheduled_event(http, event_id).await
+    }
+
     /// Deletes a [`Sticker`] by Id from the guild.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -812,8 +882,9 @@ impl PartialGuild {
         http: impl AsRef<Http>,
         emoji_id: impl Into<EmojiId>,
         name: &str,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
-        self.id.edit_emoji(http, emoji_id, name).await
+        self.id.edit_emoji(http, emoji_id, name, roles).await
     }
 
     /// Edits the properties a guild member, such as muting or nicknaming them. Returns the new
@@ -925,6 +996,27 @@ impl PartialGuild {
         self.id.edit_role_position(http, role_id, position).await
     }
 
+    /// Modifies a scheduled event in the guild with the data set, if any.
+    ///
+    /// **Note**: If the event was created by the current user, requires either [Create Events] or
+    /// the [Manage Events] permission. Otherwise, the [Manage Events] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Events]: Permissions::CREATE_EVENTS
+    /// [Manage Events]: Permissions::MANAGE_EVENTS
+    pub async fn edit_scheduled_event(
+        &self,
+        cache_http: impl CacheHttp,
+        event_id: impl Into<ScheduledEventId>,
+        builder: EditScheduledEvent<'_>,
+    ) -> Result<ScheduledEvent> {
+        self.id.edit_scheduled_event(cache_http, event_id, builder).await
+    }
+
     /// Edits a sticker.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -967,6 +1059,23 @@ impl PartialGuild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Edits the guild's membership screening form.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_member_verification(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildMemberVerification<'_>,
+    ) -> Result<GuildMemberVerification> {
+        self.id.edit_member_verification(cache_http, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1158,6 +1267,88 @@ impl PartialGuild {
         self.id.search_members(http, query, limit).await
     }
 
+    /// Fetches a specified scheduled event in the guild, by Id. If `with_user_count` is set to
+    /// `true`, then the `user_count` field will be populated, indicating the number of users
+    /// interested in the event.
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the provided id is
+    /// invalid.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn scheduled_event(
+        &self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        with_user_count: bool,
+    ) -> Result<ScheduledEvent> {
+        self.id.scheduled_event(http, event_id, with_user_count).await
+    }
+
+    /// Fetches a list of all scheduled events in the guild. If `with_user_count` is set to `true`,
+    /// then each event returned will have its `user_count` field populated.
+    ///
+    /// **Note**: Requires the [View Channel] permission at the guild level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn scheduled_events(
+        &self,
+        http: impl AsRef<Http>,
+        with_user_count: bool,
+    ) -> Result<Vec<ScheduledEvent>> {
+        self.id.scheduled_events(http, with_user_count).await
+    }
+
+    /// Fetches a list of interested users for the specified event.
+    ///
+    /// If `limit` is left unset, by default at most 100 users are returned.
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the provided Id is
+    /// invalid.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn scheduled_event_users(
+        &self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        limit: Option<u64>,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        self.id.scheduled_event_users(http, event_id, limit).await
+    }
+
+    /// Fetches a list of interested users for the specified event, with additional options and
+    /// filtering. See [`Http::get_scheduled_event_users`] for details.
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the provided Id is
+    /// invalid.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn scheduled_event_users_optioned(
+        &self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        limit: Option<u64>,
+        target: Option<UserPagination>,
+        with_member: Option<bool>,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        self.id.scheduled_event_users_optioned(http, event_id, limit, target, with_member).await
+    }
+
     /// Starts a prune of [`Member`]s.
     ///
     /// See the documentation on [`GuildPrune`] for more information.
@@ -1436,6 +1627,15 @@ impl PartialGuild {
         self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
     }
 
+    /// Returns the formatted URL of the guild's discovery splash image, if one exists.
+    #[inline]
+    #[must_use]
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        self.discovery_splash
+            .as_ref()
+            .map(|splash| cdn!("/discovery-splashes/{}/{}.webp?size=4096", self.id, splash))
+    }
+
     /// Starts an integration sync for the given integration Id.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1481,7 +1681,7 @@ impl PartialGuild {
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     /// [`Guild::vanity_url`]: crate::model::guild::Guild::vanity_url
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(http).await
     }
 
@@ -1630,6 +1830,8 @@ impl From<Guild> for PartialGuild {
             preferred_locale: guild.preferred_locale,
             max_stage_video_channel_users: guild.max_stage_video_channel_users,
             premium_progress_bar_enabled: guild.premium_progress_bar_enabled,
+            safety_alerts_channel_id: guild.safety_alerts_channel_id,
+            incidents_data: guild.incidents_data,
         }
     }
 }
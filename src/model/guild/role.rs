@@ -188,7 +188,7 @@ impl From<Role> for RoleId {
     }
 }
 
-impl<'a> From<&'a Role> for RoleId {
+impl From<&Role> for RoleId {
     /// Gets the Id of a role.
     fn from(role: &Role) -> RoleId {
         role.id
@@ -237,7 +237,7 @@ mod bool_as_option_unit {
 
     struct NullValueVisitor;
 
-    impl<'de> Visitor<'de> for NullValueVisitor {
+    impl Visitor<'_> for NullValueVisitor {
         type Value = bool;
 
         fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -293,4 +293,38 @@ mod tests {
             json!({"bot_id": null, "integration_id": null, "subscription_listing_id": null}),
         );
     }
+
+    #[test]
+    fn subscription_role_serde() {
+        let value = RoleTags {
+            bot_id: None,
+            integration_id: None,
+            premium_subscriber: false,
+            subscription_listing_id: None,
+            available_for_purchase: true,
+            guild_connections: false,
+        };
+
+        assert_json(
+            &value,
+            json!({"bot_id": null, "integration_id": null, "subscription_listing_id": null, "available_for_purchase": null}),
+        );
+    }
+
+    #[test]
+    fn guild_connections_role_serde() {
+        let value = RoleTags {
+            bot_id: None,
+            integration_id: None,
+            premium_subscriber: false,
+            subscription_listing_id: None,
+            available_for_purchase: false,
+            guild_connections: true,
+        };
+
+        assert_json(
+            &value,
+            json!({"bot_id": null, "integration_id": null, "subscription_listing_id": null, "guild_connections": null}),
+        );
+    }
 }
@@ -34,6 +34,15 @@ macro_rules! id_u64 {
                 pub fn created_at(&self) -> Timestamp {
                     Timestamp::from_discord_id(self.get())
                 }
+
+                #[doc = concat!("Creates a ", stringify!($name), " from a creation time, for use as a pagination cursor.")]
+                ///
+                /// All non-timestamp bits of the resulting ID are zero, so it sorts directly
+                /// before any ID belonging to an entity created at `timestamp` or later.
+                #[must_use]
+                pub fn from_timestamp(timestamp: Timestamp) -> Self {
+                    Self::new(timestamp.discord_id().max(1))
+                }
             }
 
             impl Default for $name {
@@ -126,43 +135,43 @@ macro_rules! id_u64 {
 }
 
 /// An identifier for an Application.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct ApplicationId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a Channel
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct ChannelId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for an Emoji
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct EmojiId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for an unspecific entity.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 // TODO: replace occurences of `#[serde(with = "snowflake")] u64` in the codebase with GenericId
 pub struct GenericId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a Guild
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct GuildId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for an Integration
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct IntegrationId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a Message
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct MessageId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a Role
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct RoleId(#[serde(with = "snowflake")] NonZeroU64);
 
@@ -171,79 +180,84 @@ pub struct RoleId(#[serde(with = "snowflake")] NonZeroU64);
 pub struct RuleId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a Scheduled Event
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct ScheduledEventId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a User
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct UserId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a [`Webhook`][super::webhook::Webhook]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct WebhookId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for an audit log entry.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct AuditLogEntryId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for an attachment.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct AttachmentId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a sticker.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct StickerId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a sticker pack.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct StickerPackId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a sticker pack banner.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct StickerPackBannerId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a SKU.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct SkuId(#[serde(with = "snowflake")] NonZeroU64);
 
+/// An identifier for a soundboard sound.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct SoundboardSoundId(#[serde(with = "snowflake")] NonZeroU64);
+
 /// An identifier for an interaction.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct InteractionId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a slash command.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct CommandId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a slash command permission Id. Can contain
 /// a [`RoleId`] or [`UserId`].
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct CommandPermissionId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a slash command version Id.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct CommandVersionId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a slash command target Id. Can contain
 /// a [`UserId`] or [`MessageId`].
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct TargetId(#[serde(with = "snowflake")] NonZeroU64);
 
 /// An identifier for a stage channel instance.
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct StageInstanceId(#[serde(with = "snowflake")] NonZeroU64);
 
@@ -251,6 +265,16 @@ pub struct StageInstanceId(#[serde(with = "snowflake")] NonZeroU64);
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct ForumTagId(#[serde(with = "snowflake")] NonZeroU64);
 
+/// An identifier for an onboarding prompt.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct OnboardingPromptId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for an onboarding prompt option.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct OnboardingPromptOptionId(#[serde(with = "snowflake")] NonZeroU64);
+
 id_u64! {
     AttachmentId;
     ApplicationId;
@@ -262,6 +286,7 @@ id_u64! {
     MessageId;
     RoleId;
     ScheduledEventId;
+    SoundboardSoundId;
     StickerId;
     StickerPackId;
     StickerPackBannerId;
@@ -277,6 +302,8 @@ id_u64! {
     StageInstanceId;
     RuleId;
     ForumTagId;
+    OnboardingPromptId;
+    OnboardingPromptOptionId;
 }
 
 /// An identifier for a Shard.
@@ -335,7 +362,7 @@ pub(crate) mod snowflake {
 
     struct SnowflakeVisitor;
 
-    impl<'de> Visitor<'de> for SnowflakeVisitor {
+    impl Visitor<'_> for SnowflakeVisitor {
         type Value = NonZeroU64;
 
         fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -371,6 +398,17 @@ mod tests {
         assert_eq!(id.created_at().to_string(), "2016-04-30T11:18:25.796Z");
     }
 
+    #[test]
+    fn test_from_timestamp() {
+        let id = GuildId::new(175928847299117063);
+        let cursor = GuildId::from_timestamp(id.created_at());
+
+        // The cursor keeps the creation time but zeroes the other bits, so it sorts directly
+        // before the original id.
+        assert_eq!(cursor.created_at(), id.created_at());
+        assert!(cursor < id);
+    }
+
     #[test]
     fn test_id_serde() {
         use serde::{Deserialize, Serialize};
@@ -402,4 +440,15 @@ mod tests {
         };
         assert_json(&s, json!({"id": "175928847299117063"}));
     }
+
+    #[test]
+    fn test_non_zero_niche() {
+        // The NonZeroU64 backing lets Option<Id> use the zero niche, so wrapping an id in Option
+        // is free.
+        assert_eq!(std::mem::size_of::<Option<GuildId>>(), std::mem::size_of::<u64>());
+
+        // Zero is not a valid snowflake and is rejected at parse time.
+        assert!("0".parse::<GuildId>().is_err());
+        assert!("175928847299117063".parse::<GuildId>().is_ok());
+    }
 }
@@ -116,7 +116,7 @@ impl Invite {
     /// # Arguments
     /// * `code` - The invite code.
     /// * `member_counts` - Whether to include information about the current number of members in
-    /// the server that the invite belongs to.
+    ///   the server that the invite belongs to.
     /// * `expiration` - Whether to include information about when the invite expires.
     /// * `event_id` - An optional server event ID to include with the invite.
     ///
@@ -283,6 +283,10 @@ pub struct RichInvite {
     pub code: String,
     /// When the invite was created.
     pub created_at: Timestamp,
+    /// When the invite will expire.
+    ///
+    /// [`None`] if the invite never expires.
+    pub expires_at: Option<Timestamp>,
     /// A representation of the minimal amount of information needed about the [`Guild`] being
     /// invited to.
     pub guild: Option<InviteGuild>,
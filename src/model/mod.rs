@@ -33,13 +33,14 @@ pub mod invite;
 pub mod mention;
 pub mod misc;
 pub mod permissions;
+pub mod soundboard;
 pub mod sticker;
 pub mod timestamp;
 pub mod user;
 pub mod voice;
 pub mod webhook;
 
-#[cfg(feature = "voice-model")]
+#[cfg(feature = "voice_model")]
 pub use serenity_voice_model as voice_gateway;
 
 pub use self::colour::{Color, Colour};
@@ -91,6 +92,7 @@ pub mod prelude {
         mention::*,
         misc::*,
         permissions::*,
+        soundboard::*,
         sticker::*,
         user::*,
         voice::*,
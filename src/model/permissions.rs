@@ -11,7 +11,7 @@
 //! Permissions follow a hierarchy:
 //! - An account can grant roles to users that are of a lower position than its highest role;
 //! - An account can edit roles lesser than its highest role, but can only grant permissions they
-//! have;
+//!   have;
 //! - An account can move only roles lesser than its highest role;
 //! - An account can only kick/ban accounts with a lesser role than its top role.
 //!
@@ -357,7 +357,7 @@ bitflags::bitflags! {
         const USE_EXTERNAL_SOUNDS = 1 << 45;
         /// Allows sending voice messages.
         const SEND_VOICE_MESSAGES = 1 << 46;
-        // Allows setting the status of a voice channel.
+        /// Allows setting the status of a voice channel.
         const SET_VOICE_CHANNEL_STATUS = 1 << 48;
 
     }
@@ -399,15 +399,20 @@ generate_get_permission_names! {
     send_messages: "Send Messages",
     send_messages_in_threads: "Send Messages in Threads",
     send_tts_messages: "Send TTS Messages",
+    send_voice_messages: "Send Voice Messages",
+    set_voice_channel_status: "Set Voice Channel Status",
     speak: "Speak",
     stream: "Stream",
     use_commands: "Use Application Commands",
     use_embedded_activities: "Use Embedded Activities",
     use_external_emojis: "Use External Emojis",
+    use_external_sounds: "Use External Sounds",
     use_external_stickers: "Use External Stickers",
+    use_soundboard: "Use Soundboard",
     use_vad: "Use Voice Activity",
     view_audit_log: "View Audit Log",
     view_channel: "View Channel",
+    view_creator_monetization_analytics: "View Creator Monetization Analytics",
     view_guild_insights: "View Guild Insights"
 }
 
@@ -497,6 +502,15 @@ impl Permissions {
         self.contains(Self::VIEW_CHANNEL)
     }
 
+    /// Shorthand for checking that the set of permissions contains the [View Creator Monetization
+    /// Analytics] permission.
+    ///
+    /// [View Creator Monetization Analytics]: Self::VIEW_CREATOR_MONETIZATION_ANALYTICS
+    #[must_use]
+    pub const fn view_creator_monetization_analytics(self) -> bool {
+        self.contains(Self::VIEW_CREATOR_MONETIZATION_ANALYTICS)
+    }
+
     /// Shorthand for checking that the set of permissions contains the [View Guild Insights]
     /// permission.
     ///
@@ -738,6 +752,24 @@ impl Permissions {
         self.contains(Self::SEND_TTS_MESSAGES)
     }
 
+    /// Shorthand for checking that the set of permissions contains the [Send Voice Messages]
+    /// permission.
+    ///
+    /// [Send Voice Messages]: Self::SEND_VOICE_MESSAGES
+    #[must_use]
+    pub const fn send_voice_messages(self) -> bool {
+        self.contains(Self::SEND_VOICE_MESSAGES)
+    }
+
+    /// Shorthand for checking that the set of permissions contains the [Set Voice Channel Status]
+    /// permission.
+    ///
+    /// [Set Voice Channel Status]: Self::SET_VOICE_CHANNEL_STATUS
+    #[must_use]
+    pub const fn set_voice_channel_status(self) -> bool {
+        self.contains(Self::SET_VOICE_CHANNEL_STATUS)
+    }
+
     /// Shorthand for checking that the set of permissions contains the [Speak] permission.
     ///
     /// [Speak]: Self::SPEAK
@@ -773,6 +805,15 @@ impl Permissions {
         self.contains(Self::USE_EXTERNAL_EMOJIS)
     }
 
+    /// Shorthand for checking that the set of permissions contains the [Use External Sounds]
+    /// permission.
+    ///
+    /// [Use External Sounds]: Self::USE_EXTERNAL_SOUNDS
+    #[must_use]
+    pub const fn use_external_sounds(self) -> bool {
+        self.contains(Self::USE_EXTERNAL_SOUNDS)
+    }
+
     /// Shorthand for checking that the set of permissions contains the [Use External Stickers]
     /// permission.
     ///
@@ -782,6 +823,15 @@ impl Permissions {
         self.contains(Self::USE_EXTERNAL_STICKERS)
     }
 
+    /// Shorthand for checking that the set of permissions contains the [Use Soundboard]
+    /// permission.
+    ///
+    /// [Use Soundboard]: Self::USE_SOUNDBOARD
+    #[must_use]
+    pub const fn use_soundboard(self) -> bool {
+        self.contains(Self::USE_SOUNDBOARD)
+    }
+
     /// Shorthand for checking that the set of permissions contains the [Use Application Commands]
     /// permission.
     ///
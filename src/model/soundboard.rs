@@ -0,0 +1,78 @@
+//! Soundboard sound model.
+
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::json::json;
+use crate::model::prelude::*;
+use crate::model::utils::default_true;
+
+/// A sound that can be played in a voice channel from a guild's soundboard.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SoundboardSound {
+    /// The name of the sound.
+    pub name: String,
+    /// The unique Id of the sound.
+    pub sound_id: SoundboardSoundId,
+    /// The volume the sound plays at, between 0 and 1.
+    pub volume: f64,
+    /// The Id of the sound's custom emoji, if any.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the sound's standard emoji, if any.
+    pub emoji_name: Option<String>,
+    /// The Id of the guild the sound belongs to. Not present for default sounds.
+    pub guild_id: Option<GuildId>,
+    /// Whether the sound can be used; may be false due to loss of server boosts.
+    #[serde(default = "default_true")]
+    pub available: bool,
+    /// The user who created the sound.
+    ///
+    /// **Note**: Only present if the bot has the [Create Guild Expressions] or [Manage Guild
+    /// Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub user: Option<User>,
+}
+
+#[cfg(feature = "model")]
+impl SoundboardSound {
+    /// Retrieves the URL to the sound's audio file.
+    #[inline]
+    #[must_use]
+    pub fn url(&self) -> String {
+        cdn!("/soundboard-sounds/{}", self.sound_id)
+    }
+
+    /// Plays the sound in the given voice channel.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, as well as [Use External
+    /// Sounds] for sounds from other guilds, and the current user must be connected to the voice
+    /// channel without being muted or deafened.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    /// [Use External Sounds]: Permissions::USE_EXTERNAL_SOUNDS
+    pub async fn play(
+        &self,
+        http: impl AsRef<Http>,
+        channel_id: impl Into<ChannelId>,
+    ) -> Result<()> {
+        let map = json!({
+            "sound_id": self.sound_id,
+            "source_guild_id": self.guild_id,
+        });
+
+        http.as_ref().send_soundboard_sound(channel_id.into(), &map).await
+    }
+}
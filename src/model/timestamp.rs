@@ -3,6 +3,11 @@
 //! The [`Timestamp`] newtype wraps `chrono::DateTime<Utc>` or `time::OffsetDateTime` if the `time`
 //! feature is enabled.
 //!
+//! All date-time fields of the model types, such as [`Message::timestamp`] and
+//! [`Member::joined_at`], use this type. The creation time encoded in a snowflake can be
+//! extracted as a [`Timestamp`] via the `created_at` method of the respective Id type, e.g.
+//! [`GuildId::created_at`].
+//!
 //! # Formatting
 //! ```
 //! # use serenity::model::id::GuildId;
@@ -28,6 +33,10 @@
 //! assert!(Timestamp::parse("2016-04-30T11:18:25").is_err());
 //! assert!(Timestamp::parse("2016-04-30T11:18").is_err());
 //! ```
+//!
+//! [`Message::timestamp`]: crate::model::channel::Message::timestamp
+//! [`Member::joined_at`]: crate::model::guild::Member::joined_at
+//! [`GuildId::created_at`]: crate::model::id::GuildId::created_at
 
 use std::fmt;
 use std::str::FromStr;
@@ -78,6 +87,18 @@ impl Timestamp {
         Self::from_millis(((id >> 22) + DISCORD_EPOCH) as i64).expect("can't fail")
     }
 
+    /// The inverse of [`Self::from_discord_id`]: the smallest snowflake with this creation time.
+    /// Times before the Discord epoch (2015-01-01) are clamped to zero.
+    pub(crate) fn discord_id(&self) -> u64 {
+        #[cfg(feature = "chrono")]
+        let millis = self.0.timestamp_millis();
+        #[cfg(not(feature = "chrono"))]
+        let millis = (self.0.unix_timestamp_nanos() / 1_000_000) as i64;
+
+        let millis = millis.saturating_sub(DISCORD_EPOCH as i64).max(0) as u64;
+        millis << 22
+    }
+
     /// Create a new `Timestamp` with the current date and time in UTC.
     #[must_use]
     pub fn now() -> Self {
@@ -172,6 +193,12 @@ impl From<OffsetDateTime> for Timestamp {
     }
 }
 
+impl From<std::time::SystemTime> for Timestamp {
+    fn from(st: std::time::SystemTime) -> Self {
+        Self(st.into())
+    }
+}
+
 impl Default for Timestamp {
     fn default() -> Self {
         #[cfg(feature = "chrono")]
@@ -243,4 +270,12 @@ mod tests {
             assert_eq!(timestamp.to_string(), "2016-04-30T11:18:25Z");
         }
     }
+
+    #[test]
+    fn comparison() {
+        let early = Timestamp::from_unix_timestamp(1462015105).unwrap();
+        let late = Timestamp::from_unix_timestamp(1462015106).unwrap();
+        assert!(early < late);
+        assert_eq!(early, Timestamp::parse("2016-04-30T11:18:25Z").unwrap());
+    }
 }
@@ -60,7 +60,7 @@ pub(crate) mod discriminator {
 
     struct DiscriminatorVisitor;
 
-    impl<'de> Visitor<'de> for DiscriminatorVisitor {
+    impl Visitor<'_> for DiscriminatorVisitor {
         type Value = u16;
 
         fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -86,7 +86,7 @@ pub(crate) mod discriminator {
         deserializer.deserialize_option(OptionalDiscriminatorVisitor)
     }
 
-    #[allow(clippy::trivially_copy_pass_by_ref)]
+    #[allow(clippy::trivially_copy_pass_by_ref, clippy::ref_option)]
     pub fn serialize<S: Serializer>(
         value: &Option<NonZeroU16>,
         serializer: S,
@@ -248,6 +248,9 @@ pub struct User {
     pub global_name: Option<String>,
     /// Optional avatar hash.
     pub avatar: Option<ImageHash>,
+    /// The user's avatar decoration, if one is set.
+    #[serde(default)]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
     /// Indicator of whether the user is a bot.
     #[serde(default)]
     pub bot: bool,
@@ -293,6 +296,19 @@ pub struct User {
     pub member: Option<Box<PartialMember>>,
 }
 
+/// The data for a [`User`]'s avatar decoration.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#avatar-decoration-data-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AvatarDecorationData {
+    /// The avatar decoration hash.
+    pub asset: String,
+    /// The Id of the avatar decoration's SKU.
+    pub sku_id: SkuId,
+}
+
 enum_number! {
     /// Premium types denote the level of premium a user has. Visit the [Nitro](https://discord.com/nitro)
     /// page to learn more about the premium plans Discord currently offers.
@@ -381,6 +397,17 @@ impl User {
         avatar_url(None, self.id, self.avatar.as_ref())
     }
 
+    /// Returns the formatted URL of the user's avatar decoration, if one is set.
+    ///
+    /// This will produce a PNG URL.
+    #[inline]
+    #[must_use]
+    pub fn avatar_decoration_url(&self) -> Option<String> {
+        self.avatar_decoration_data
+            .as_ref()
+            .map(|data| cdn!("/avatar-decoration-presets/{}.png", data.asset))
+    }
+
     /// Returns the formatted URL of the user's banner, if one exists.
     ///
     /// This will produce a WEBP image URL, or GIF if the user has a GIF banner.
@@ -579,6 +606,14 @@ impl User {
         tag(&self.name, self.discriminator)
     }
 
+    /// Returns the name as shown in chat: the user's global display name, if set, and their
+    /// username otherwise.
+    #[inline]
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.global_name.as_deref().unwrap_or(&self.name)
+    }
+
     /// Returns the user's nickname in the given `guild_id`.
     ///
     /// If none is used, it returns [`None`].
@@ -722,7 +757,7 @@ impl From<Member> for UserId {
     }
 }
 
-impl<'a> From<&'a Member> for UserId {
+impl From<&Member> for UserId {
     /// Gets the Id of a [`Member`].
     fn from(member: &Member) -> UserId {
         member.user.id
@@ -736,7 +771,7 @@ impl From<User> for UserId {
     }
 }
 
-impl<'a> From<&'a User> for UserId {
+impl From<&User> for UserId {
     /// Gets the Id of a [`User`].
     fn from(user: &User) -> UserId {
         user.id
@@ -776,7 +811,7 @@ fn tag(name: &str, discriminator: Option<NonZeroU16>) -> String {
     tag.push_str(name);
     if let Some(discriminator) = discriminator {
         tag.push('#');
-        write!(tag, "{discriminator:04}").unwrap();
+        write!(tag, "{discriminator:04}").expect("writing to a String cannot fail");
     }
     tag
 }
@@ -855,6 +890,12 @@ mod test {
             // New username system
             assert!(user.default_avatar_url().ends_with("5.png"));
 
+            // New username system, with an index that wraps around
+            user.id = UserId::new(2 << 22);
+            assert!(user.default_avatar_url().ends_with("2.png"));
+            user.id = UserId::new(6 << 22);
+            assert!(user.default_avatar_url().ends_with("0.png"));
+
             // Legacy username system
             user.discriminator = NonZeroU16::new(1);
             assert!(user.default_avatar_url().ends_with("1.png"));
@@ -864,6 +905,8 @@ mod test {
             assert!(user.default_avatar_url().ends_with("3.png"));
             user.discriminator = NonZeroU16::new(4);
             assert!(user.default_avatar_url().ends_with("4.png"));
+            user.discriminator = NonZeroU16::new(5);
+            assert!(user.default_avatar_url().ends_with("0.png"));
         }
     }
 }
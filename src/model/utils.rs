@@ -139,12 +139,23 @@ pub mod presences {
 pub fn deserialize_buttons<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> StdResult<Vec<ActivityButton>, D::Error> {
-    Vec::deserialize(deserializer).map(|labels| {
-        labels
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum LabelOrButton {
+        // Bots only receive the labels of the buttons in presence payloads.
+        Label(String),
+        Button(ActivityButton),
+    }
+
+    Vec::deserialize(deserializer).map(|buttons| {
+        buttons
             .into_iter()
-            .map(|l| ActivityButton {
-                label: l,
-                url: String::new(),
+            .map(|button| match button {
+                LabelOrButton::Label(label) => ActivityButton {
+                    label,
+                    url: String::new(),
+                },
+                LabelOrButton::Button(button) => button,
             })
             .collect()
     })
@@ -207,6 +218,48 @@ pub mod comma_separated_string {
     }
 }
 
+/// Used with `#[serde(with = "hex_colours")]`.
+///
+/// Discord sends the colours of super reactions as `#RRGGBB` strings rather than as integers.
+pub mod hex_colours {
+    use serde::de::Error;
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::model::Colour;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Colour>, D::Error> {
+        let strings: Vec<String> = Vec::deserialize(deserializer)?;
+
+        strings
+            .iter()
+            .map(|string| {
+                let hex = string
+                    .strip_prefix('#')
+                    .ok_or_else(|| Error::custom("expected hex colour to start with '#'"))?;
+
+                u32::from_str_radix(hex, 16).map(Colour::new).map_err(Error::custom)
+            })
+            .collect()
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(
+        colours: &Vec<Colour>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(colours.len()))?;
+
+        for colour in colours {
+            seq.serialize_element(&format!("#{:06X}", colour.0))?;
+        }
+
+        seq.end()
+    }
+}
+
 /// Used with `#[serde(with = "single_recipient")]`
 pub mod single_recipient {
     use serde::de::Error;
@@ -246,6 +299,7 @@ pub mod secret {
         Option::<S>::deserialize(deserializer).map(|s| s.map(Secret::new))
     }
 
+    #[allow(clippy::ref_option)] // serde passes the field by reference
     pub fn serialize<S: Serialize + Zeroize, Sr: Serializer>(
         secret: &Option<Secret<S>>,
         serializer: Sr,
@@ -10,6 +10,7 @@ use crate::model::Timestamp;
 /// Information about an available voice region.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/voice#voice-region-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct VoiceRegion {
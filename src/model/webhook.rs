@@ -1,4 +1,30 @@
 //! Webhook model and implementations.
+//!
+//! # Webhook-only usage
+//!
+//! Executing webhooks does not require a bot token, a gateway connection, or the cache; a
+//! token-less [`Http`] instance carries its own ratelimiter and is all that is needed:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "model")]
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use serenity::builder::ExecuteWebhook;
+//! use serenity::http::Http;
+//! use serenity::model::webhook::Webhook;
+//!
+//! let http = Http::new("");
+//! let webhook =
+//!     Webhook::from_url(&http, "https://discord.com/api/webhooks/133742013374206969/hello-there")
+//!         .await?;
+//!
+//! let builder = ExecuteWebhook::new().content("hello there").username("Webhook test");
+//! webhook.execute(&http, false, builder).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Such applications can disable serenity's default features and enable only `model` and a TLS
+//! backend, leaving out the gateway and the cache entirely.
 
 #[cfg(feature = "model")]
 use secrecy::ExposeSecret;
@@ -269,7 +295,9 @@ impl Webhook {
     /// # use serenity::model::{webhook::Webhook, id::WebhookId};
     /// #
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let http: Http = unimplemented!();
+    /// // A token-less Http instance is sufficient for webhook-only usage.
+    /// let http = Http::new("");
+    ///
     /// let id = WebhookId::new(245037420704169985);
     /// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
     ///
@@ -305,7 +333,9 @@ impl Webhook {
     /// # use serenity::model::webhook::Webhook;
     /// #
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let http: Http = unimplemented!();
+    /// // A token-less Http instance is sufficient for webhook-only usage.
+    /// let http = Http::new("");
+    ///
     /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
     /// let webhook = Webhook::from_url(&http, url).await?;
     /// # Ok(())
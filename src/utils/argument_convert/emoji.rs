@@ -13,7 +13,7 @@ pub enum EmojiParseError {
     /// Guild was not in cache, or guild HTTP request failed.
     FailedToRetrieveGuild,
     /// The provided emoji string failed to parse, or the parsed result cannot be found in the
-    /// guild roles.
+    /// guild emojis.
     NotFoundOrMalformed,
 }
 
@@ -34,6 +34,21 @@ use crate::prelude::*;
 /// Trait implementations may do network requests as part of their parsing procedure.
 ///
 /// Useful for implementing argument parsing in command frameworks.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::model::prelude::*;
+/// # use serenity::prelude::*;
+/// use serenity::utils::ArgumentConvert;
+///
+/// # async fn run(ctx: &Context, msg: &Message) -> Result<(), Box<dyn std::error::Error>> {
+/// // Resolve a user from a mention, ID, name or tag typed into a command.
+/// let arg = "@kangalioo";
+/// let user = User::convert(ctx, msg.guild_id, Some(msg.channel_id), arg).await?;
+/// # Ok(())
+/// # }
+/// ```
 #[async_trait::async_trait]
 pub trait ArgumentConvert: Sized {
     /// The associated error which can be returned from parsing.
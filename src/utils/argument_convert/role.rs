@@ -14,7 +14,7 @@ pub enum RoleParseError {
     NotInCache,
     /// HTTP error while retrieving guild roles.
     Http(SerenityError),
-    /// The provided channel string failed to parse, or the parsed result cannot be found in the
+    /// The provided role string failed to parse, or the parsed result cannot be found in the
     /// cache.
     NotFoundOrMalformed,
 }
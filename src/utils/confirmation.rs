@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::builder::{
+    CreateActionRow,
+    CreateButton,
+    CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+    CreateMessage,
+};
+use crate::client::Context;
+use crate::collector::ComponentInteractionCollector;
+use crate::model::prelude::*;
+
+/// Convenience builder to ask a user for confirmation via Yes/No buttons.
+///
+/// A message with the prompt and two buttons is sent, and the first press by the target user
+/// decides the outcome. The buttons are disabled once a choice was made or the prompt timed out.
+///
+/// ```rust,no_run
+/// # use serenity::{model::prelude::*, prelude::*, utils::CreateConfirmation, Result};
+/// # async fn _foo(ctx: &Context, channel_id: ChannelId, user_id: UserId) -> Result<()> {
+/// let confirmed = CreateConfirmation::new("Ban @spammer?")
+///     .timeout(std::time::Duration::from_secs(30))
+///     .execute(ctx, channel_id, user_id)
+///     .await?;
+///
+/// if confirmed == Some(true) {
+///     // carry out the action
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "collector")]
+#[must_use]
+pub struct CreateConfirmation {
+    prompt: String,
+    yes_label: String,
+    no_label: String,
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature = "collector")]
+impl CreateConfirmation {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            yes_label: String::from("Yes"),
+            no_label: String::from("No"),
+            timeout: None,
+        }
+    }
+
+    /// Sets the label of the confirming button. Defaults to "Yes".
+    pub fn yes_label(mut self, label: impl Into<String>) -> Self {
+        self.yes_label = label.into();
+        self
+    }
+
+    /// Sets the label of the declining button. Defaults to "No".
+    pub fn no_label(mut self, label: impl Into<String>) -> Self {
+        self.no_label = label.into();
+        self
+    }
+
+    /// Sets how long to wait for the user's choice.
+    ///
+    /// You should almost always set a timeout here. Otherwise, the prompt will wait for an
+    /// answer forever.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the prompt to `channel_id` and awaits the choice of `user_id`.
+    ///
+    /// Returns `Some(true)` if the user confirmed, `Some(false)` if they declined, and [`None`]
+    /// if the prompt timed out. Button presses by other users are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if sending the prompt or acknowledging the choice fails.
+    ///
+    /// [`Error::Http`]: crate::Error::Http
+    pub async fn execute(
+        self,
+        ctx: &Context,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> Result<Option<bool>, crate::Error> {
+        let buttons = |disabled: bool| {
+            CreateActionRow::Buttons(vec![
+                CreateButton::new("confirmation_yes")
+                    .label(&self.yes_label)
+                    .style(ButtonStyle::Success)
+                    .disabled(disabled),
+                CreateButton::new("confirmation_no")
+                    .label(&self.no_label)
+                    .style(ButtonStyle::Danger)
+                    .disabled(disabled),
+            ])
+        };
+
+        let builder = CreateMessage::new().content(&self.prompt).components(vec![buttons(false)]);
+        let mut message = channel_id.send_message(&ctx.http, builder).await?;
+
+        let mut collector = ComponentInteractionCollector::new(&ctx.shard)
+            .message_id(message.id)
+            .author_id(user_id)
+            .custom_ids(vec![String::from("confirmation_yes"), String::from("confirmation_no")]);
+
+        if let Some(timeout) = self.timeout {
+            collector = collector.timeout(timeout);
+        }
+
+        let Some(interaction) = collector.next().await else {
+            message
+                .edit(ctx, crate::builder::EditMessage::new().components(vec![buttons(true)]))
+                .await?;
+
+            return Ok(None);
+        };
+
+        let builder = CreateInteractionResponseMessage::new().components(vec![buttons(true)]);
+        interaction
+            .create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(builder))
+            .await?;
+
+        Ok(Some(interaction.data.custom_id == "confirmation_yes"))
+    }
+}
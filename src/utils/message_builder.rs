@@ -4,6 +4,8 @@ use std::ops::Add;
 use crate::model::guild::Emoji;
 use crate::model::id::{ChannelId, RoleId, UserId};
 use crate::model::mention::Mentionable;
+use crate::model::Timestamp;
+use crate::utils::{FormattedTimestamp, FormattedTimestampStyle};
 
 /// The Message Builder is an ergonomic utility to easily build a message, by adding text and
 /// mentioning mentionable structs.
@@ -159,6 +161,34 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a timestamp as Discord's `<t:unix:style>` markdown, which clients render as a
+    /// date and/or time in the reader's locale and timezone.
+    ///
+    /// Accepts anything convertible into a [`Timestamp`], such as a [`std::time::SystemTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Timestamp;
+    /// use serenity::utils::{FormattedTimestampStyle, MessageBuilder};
+    ///
+    /// let timestamp = Timestamp::from_unix_timestamp(1462015105).unwrap();
+    /// let content = MessageBuilder::new()
+    ///     .push("Event starts ")
+    ///     .push_timestamp(timestamp, FormattedTimestampStyle::RelativeTime)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Event starts <t:1462015105:R>");
+    /// ```
+    pub fn push_timestamp(
+        &mut self,
+        timestamp: impl Into<Timestamp>,
+        style: FormattedTimestampStyle,
+    ) -> &mut Self {
+        self._push(&FormattedTimestamp::new(timestamp.into(), Some(style)));
+        self
+    }
+
     /// Pushes a string to the internal message content.
     ///
     /// Note that this does not mutate either the given data or the internal message content in
@@ -187,7 +217,7 @@ impl MessageBuilder {
 
     #[inline]
     fn _push<C: std::fmt::Display + ?Sized>(&mut self, content: &C) -> &mut Self {
-        write!(self.0, "{content}").unwrap();
+        write!(self.0, "{content}").expect("writing to a String cannot fail");
 
         self
     }
@@ -353,6 +383,69 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a heading to the content.
+    ///
+    /// `level` is clamped between 1 (largest) and 3 (smallest), matching the heading levels
+    /// Discord renders. A heading spans the whole line, so this should usually be followed by a
+    /// newline; see [`Self::push_heading_line`].
+    pub fn push_heading(&mut self, level: u8, content: impl Into<Content>) -> &mut Self {
+        for _ in 0..level.clamp(1, 3) {
+            self.0.push('#');
+        }
+        self.0.push(' ');
+        self._push(&content.into());
+
+        self
+    }
+
+    /// Pushes a bullet list to the content, one line per item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_bullet_list(["milk", "eggs"]).build();
+    ///
+    /// assert_eq!(content, "- milk\n- eggs\n");
+    /// ```
+    pub fn push_bullet_list(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<Content>>,
+    ) -> &mut Self {
+        for item in items {
+            self.0.push_str("- ");
+            self._push(&item.into());
+            self.0.push('\n');
+        }
+
+        self
+    }
+
+    /// Pushes a numbered list to the content, one line per item, numbered from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_numbered_list(["milk", "eggs"]).build();
+    ///
+    /// assert_eq!(content, "1. milk\n2. eggs\n");
+    /// ```
+    pub fn push_numbered_list(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<Content>>,
+    ) -> &mut Self {
+        for (i, item) in items.into_iter().enumerate() {
+            write!(self.0, "{}. ", i + 1).expect("writing to a String cannot fail");
+            self._push(&item.into());
+            self.0.push('\n');
+        }
+
+        self
+    }
+
     /// Pushes the given text with a newline appended to the content.
     ///
     /// # Examples
@@ -513,6 +606,26 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a heading with an added newline to the content.
+    ///
+    /// # Examples
+    ///
+    /// Push content and then append a newline:
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_heading_line(2, "Rules").push("Be nice.").build();
+    ///
+    /// assert_eq!(content, "## Rules\nBe nice.");
+    /// ```
+    pub fn push_heading_line(&mut self, level: u8, content: impl Into<Content>) -> &mut Self {
+        self.push_heading(level, content);
+        self.0.push('\n');
+
+        self
+    }
+
     /// Pushes text to your message, but normalizing content - that means ensuring that there's no
     /// unwanted formatting, mention spam etc.
     pub fn push_safe(&mut self, content: impl Into<Content>) -> &mut Self {
@@ -640,6 +753,57 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a heading to the content normalizing content.
+    pub fn push_heading_safe(&mut self, level: u8, content: impl Into<Content>) -> &mut Self {
+        for _ in 0..level.clamp(1, 3) {
+            self.0.push('#');
+        }
+        self.0.push(' ');
+        {
+            let mut c = content.into();
+            c.inner = normalize(&c.inner).replace('\n', " ");
+            self._push(&c);
+        }
+
+        self
+    }
+
+    /// Pushes a bullet list to the content normalizing each item.
+    pub fn push_bullet_list_safe(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<Content>>,
+    ) -> &mut Self {
+        for item in items {
+            self.0.push_str("- ");
+            {
+                let mut c = item.into();
+                c.inner = normalize(&c.inner).replace('\n', " ");
+                self._push(&c);
+            }
+            self.0.push('\n');
+        }
+
+        self
+    }
+
+    /// Pushes a numbered list to the content normalizing each item.
+    pub fn push_numbered_list_safe(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<Content>>,
+    ) -> &mut Self {
+        for (i, item) in items.into_iter().enumerate() {
+            write!(self.0, "{}. ", i + 1).expect("writing to a String cannot fail");
+            {
+                let mut c = item.into();
+                c.inner = normalize(&c.inner).replace('\n', " ");
+                self._push(&c);
+            }
+            self.0.push('\n');
+        }
+
+        self
+    }
+
     /// Pushes text with a newline appended to the content normalizing content.
     ///
     /// # Examples
@@ -931,7 +1095,8 @@ pub trait EmbedMessageBuilding {
 
 impl EmbedMessageBuilding for MessageBuilder {
     fn push_named_link(&mut self, name: impl Into<Content>, url: impl Into<Content>) -> &mut Self {
-        write!(self.0, "[{}]({})", name.into(), url.into()).unwrap();
+        write!(self.0, "[{}]({})", name.into(), url.into())
+            .expect("writing to a String cannot fail");
 
         self
     }
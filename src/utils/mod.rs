@@ -3,12 +3,16 @@
 
 #[cfg(feature = "client")]
 mod argument_convert;
+#[cfg(feature = "collector")]
+mod confirmation;
 #[cfg(feature = "cache")]
 mod content_safe;
 mod custom_message;
 mod formatted_timestamp;
 mod message_builder;
 #[cfg(feature = "collector")]
+mod pagination;
+#[cfg(feature = "collector")]
 mod quick_modal;
 
 pub mod token;
@@ -17,17 +21,26 @@ use std::num::NonZeroU16;
 
 #[cfg(feature = "client")]
 pub use argument_convert::*;
+#[cfg(feature = "collector")]
+pub use confirmation::*;
 #[cfg(feature = "cache")]
 pub use content_safe::*;
 pub use formatted_timestamp::*;
 #[cfg(feature = "collector")]
+pub use pagination::*;
+#[cfg(feature = "collector")]
 pub use quick_modal::*;
 use url::Url;
 
 pub use self::custom_message::CustomMessage;
 pub use self::message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder};
 #[doc(inline)]
-pub use self::token::validate as validate_token;
+pub use self::token::{
+    parse as parse_token,
+    validate as validate_token,
+    InvalidToken,
+    TokenComponents,
+};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model"))]
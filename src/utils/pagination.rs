@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use crate::builder::{
+    CreateActionRow,
+    CreateButton,
+    CreateEmbed,
+    CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+    CreateMessage,
+    EditMessage,
+};
+use crate::client::Context;
+use crate::collector::ComponentInteractionCollector;
+use crate::model::prelude::*;
+
+/// Convenience builder to present a list of embed pages as a button-driven menu.
+///
+/// The menu is sent as a message with first/previous/next/last buttons below the current page,
+/// and the component interaction loop is handled internally. Once no button is pressed for the
+/// configured timeout, the buttons are disabled and the menu stops responding.
+///
+/// ```rust,no_run
+/// # use serenity::{builder::*, model::prelude::*, prelude::*, utils::CreatePagination, Result};
+/// # async fn _foo(ctx: &Context, channel_id: ChannelId, user_id: UserId) -> Result<()> {
+/// let pages = vec![
+///     CreateEmbed::new().title("Page 1").description("Introduction"),
+///     CreateEmbed::new().title("Page 2").description("Details"),
+/// ];
+///
+/// CreatePagination::new(pages)
+///     .timeout(std::time::Duration::from_secs(60))
+///     .execute(ctx, channel_id, user_id)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "collector")]
+#[must_use]
+pub struct CreatePagination {
+    pages: Vec<CreateEmbed>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature = "collector")]
+impl CreatePagination {
+    pub fn new(pages: Vec<CreateEmbed>) -> Self {
+        Self {
+            pages,
+            timeout: None,
+        }
+    }
+
+    /// Sets how long the menu waits for the next button press before shutting down.
+    ///
+    /// You should almost always set a timeout here. Otherwise, the menu will wait for button
+    /// presses forever.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the menu to `channel_id` and handles the interaction loop until it times out.
+    ///
+    /// Only button presses by `user_id` flip through the pages; presses by other users are
+    /// ignored. Once the menu times out, the buttons are disabled and the menu's message is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Other`] if no pages were provided, or [`Error::Http`] if sending or
+    /// editing the menu's message fails.
+    ///
+    /// [`Error::Other`]: crate::Error::Other
+    /// [`Error::Http`]: crate::Error::Http
+    pub async fn execute(
+        self,
+        ctx: &Context,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> Result<Message, crate::Error> {
+        if self.pages.is_empty() {
+            return Err(crate::Error::Other("a paginated menu requires at least one page"));
+        }
+
+        let count = self.pages.len();
+        let mut page = 0;
+
+        let builder = CreateMessage::new()
+            .embed(self.pages[page].clone())
+            .components(vec![Self::action_row(page, count, false)]);
+        let mut message = channel_id.send_message(&ctx.http, builder).await?;
+
+        loop {
+            let mut collector = ComponentInteractionCollector::new(&ctx.shard)
+                .message_id(message.id)
+                .author_id(user_id);
+
+            if let Some(timeout) = self.timeout {
+                collector = collector.timeout(timeout);
+            }
+
+            let Some(interaction) = collector.next().await else { break };
+
+            page = match interaction.data.custom_id.as_str() {
+                "pagination_first" => 0,
+                "pagination_previous" => page.saturating_sub(1),
+                "pagination_next" => std::cmp::min(page + 1, count - 1),
+                "pagination_last" => count - 1,
+                _ => page,
+            };
+
+            let builder = CreateInteractionResponseMessage::new()
+                .embed(self.pages[page].clone())
+                .components(vec![Self::action_row(page, count, false)]);
+            interaction
+                .create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(builder))
+                .await?;
+        }
+
+        message
+            .edit(ctx, EditMessage::new().components(vec![Self::action_row(page, count, true)]))
+            .await?;
+
+        Ok(message)
+    }
+
+    fn action_row(page: usize, count: usize, disabled: bool) -> CreateActionRow {
+        let button = |id: &str, label: &str, disabled: bool| {
+            CreateButton::new(id).label(label).disabled(disabled)
+        };
+
+        CreateActionRow::Buttons(vec![
+            button("pagination_first", "<<", disabled || page == 0),
+            button("pagination_previous", "<", disabled || page == 0),
+            // Not an actual control; displays the current position in the menu.
+            button("pagination_position", &format!("{}/{}", page + 1, count), true),
+            button("pagination_next", ">", disabled || page + 1 == count),
+            button("pagination_last", ">>", disabled || page + 1 == count),
+        ])
+    }
+}
@@ -2,12 +2,17 @@
 
 use std::{fmt, str};
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+
+use crate::model::id::UserId;
+use crate::model::Timestamp;
+
 /// Validates that a token is likely in a valid format.
 ///
 /// This performs the following checks on a given token:
 /// - Is not empty;
-/// - Contains 3 parts (split by the period char `'.'`);
-/// - The second part of the token is at least 6 characters long;
+/// - Contains 3 non-empty parts (split by the period char `'.'`);
 ///
 /// # Examples
 ///
@@ -43,6 +48,71 @@ pub fn validate(token: impl AsRef<str>) -> Result<(), InvalidToken> {
     }
 }
 
+/// The components encoded in a bot token.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TokenComponents {
+    /// The ID of the bot user the token belongs to.
+    ///
+    /// For bots created through the application page, this is equal to the application ID.
+    pub user_id: UserId,
+    /// The time at which the token was generated, if it could be decoded.
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Parses the components encoded in a token, without checking the token's authenticity.
+///
+/// A bot's user ID is equal to its application ID for bots created through the application page,
+/// so this can be used to derive the application ID before connecting.
+///
+/// # Examples
+///
+/// ```
+/// use serenity::utils::token::parse;
+///
+/// let components =
+///     parse("MTAxNzI4NDc3NDQ5NDM5NjQ5Ng.GCg2TY.hDYXBuci5RPCKjCNmBbqSK2DYvKarDS").unwrap();
+/// assert_eq!(components.user_id.get(), 1017284774494396496);
+///
+/// assert!(parse("not a token").is_none());
+/// ```
+#[must_use]
+pub fn parse(token: impl AsRef<str>) -> Option<TokenComponents> {
+    // Tokens can be preceded by "Bot " (that's how the Discord API expects them)
+    let mut parts = token.as_ref().trim_start_matches("Bot ").split('.');
+
+    let user_id = parts.next()?;
+    let user_id = URL_SAFE_NO_PAD.decode(user_id).ok()?;
+    let user_id = str::from_utf8(&user_id).ok()?.parse().ok()?;
+
+    // The second part encodes the time at which the token was generated: big-endian seconds,
+    // relative to the token epoch (2011-01-01) in old four-byte tokens and to the Unix epoch
+    // otherwise.
+    let timestamp = URL_SAFE_NO_PAD.decode(parts.next()?).ok().and_then(|bytes| {
+        const TOKEN_EPOCH: i64 = 1_293_840_000;
+
+        let mut secs: i64 = 0;
+        for byte in bytes {
+            secs = secs.checked_mul(256)? + i64::from(byte);
+        }
+        if secs < TOKEN_EPOCH {
+            secs += TOKEN_EPOCH;
+        }
+        Timestamp::from_unix_timestamp(secs).ok()
+    });
+
+    // The third part is the HMAC; its contents cannot be verified offline, but it must be present.
+    let hmac = parts.next()?;
+    if hmac.is_empty() || parts.next().is_some() {
+        return None;
+    }
+
+    Some(TokenComponents {
+        user_id,
+        timestamp,
+    })
+}
+
 /// Error that can be return by [`validate`].
 #[derive(Debug)]
 pub struct InvalidToken;